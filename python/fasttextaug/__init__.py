@@ -1,2 +1,2 @@
-from . import utils, augmenter
+from . import utils, augmenter, flow, config
 from . import rust_fasttextaug as rust_fasttextaug
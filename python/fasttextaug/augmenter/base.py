@@ -1,21 +1,61 @@
-from typing import List, Union
+from typing import List, Tuple, Union
 
 
 class BaseAug:
     def get_rust_api_object(self):
         raise NotImplemented
 
-    def augment(self, data: Union[List[str], str], n=1, num_thread=1) -> List[str]:
+    def get_config(self, format: str = "json") -> str:
+        """
+        Config of augmenter in `format`: 'json' or 'toml'. It contains seed of augmenter
+        (drawn at random, if it wasn't passed), so augmenter created from config reproduces the same results
+        """
+        return self.get_rust_api_object().get_config(format)
+
+    def augment(self, data: Union[List[str], str], n=1, num_thread=1, seed=None) -> List[str]:
+        """
+        Augment list of values or `n` times augment single value.
+        With `seed` result is reproducible and does not depend on `num_thread`.
+        If `seed` is None, seed of augmenter is used
+        """
         if isinstance(data, list):
             if num_thread == 1:
-                aug_result = self.get_rust_api_object().augment_list_single_thread(data)
+                aug_result = self.get_rust_api_object().augment_list_single_thread(data, seed)
             else:
-                aug_result = self.get_rust_api_object().augment_list_multi_thread(data, num_thread)
+                aug_result = self.get_rust_api_object().augment_list_multi_thread(data, num_thread, seed)
         else:
             if num_thread == 1:
-                aug_result = self.get_rust_api_object().augment_string_single_thread(data, n)
+                aug_result = self.get_rust_api_object().augment_string_single_thread(data, n, seed)
             else:
                 aug_result = self.get_rust_api_object().augment_string_multi_thread(
-                    data, n, num_thread
+                    data, n, num_thread, seed
                 )
         return aug_result
+
+    def augment_with_alignment(
+        self, data: Union[List[str], str], seed=None
+    ) -> List[Tuple[str, List[Tuple[Tuple[int, int], Tuple[int, int]]]]]:
+        """
+        Augment values, for each value also return alignment of its tokens:
+        list of ((original start, original end), (augmented start, augmented end)) char spans.
+        Inserted token has empty original span, removed token has empty augmented span,
+        split token and replaced range of tokens ('New York' -> 'NYC') are aligned as one span.
+        Useful to move NER / QA labels onto augmented text
+        """
+        if isinstance(data, str):
+            data = [data]
+        return self.get_rust_api_object().augment_with_alignment(data, seed)
+
+    def augment_labeled(
+        self, tokens: List[List[str]], labels: List[List[str]], default_label: str = "O", seed=None
+    ) -> List[Tuple[List[str], List[str]]]:
+        """
+        Augment pre-tokenized sequences with label (e.g. BIO/NER tag) per token.
+        Returns pairs (augmented tokens, their labels).
+        Substituted, swapped and split tokens keep their label ('B-' becomes 'I-' for the next parts of split token),
+        deleted tokens are dropped together with label, inserted tokens get `default_label`.
+        Raises ValueError, if token is empty or contains whitespace.
+
+        >>> aug.augment_labeled([['Anna', 'lives', 'in', 'Paris']], [['B-PER', 'O', 'O', 'B-LOC']])
+        """
+        return self.get_rust_api_object().augment_labeled(list(zip(tokens, labels)), default_label, seed)
@@ -1,3 +1,5 @@
 from .ocr import OcrAug
 from .keyboard import KeyboardAug
 from .random_char import RandomCharAug
+from .homoglyph import HomoglyphAug
+from .confusion import ConfusionAug
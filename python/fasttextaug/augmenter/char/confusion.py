@@ -0,0 +1,93 @@
+from typing import List
+
+from fasttextaug.rust_fasttextaug import RustConfusionApiClass
+
+from ..base import BaseAug
+
+
+class ConfusionAug(BaseAug):
+    """
+    Augmenter that simulate errors of your own OCR/ASR system. Confusion model is learned from pairs of\
+        ground-truth and noisy texts, so substitutions, insertions and deletions are drawn with their observed frequency.
+
+    :param str model_path: Path of confusion model, created by `ConfusionAug.train_and_save`
+        or any weighted mapping, e.g. {"l": {"1": 0.7, "I": 0.3}}
+    :param int aug_char_min: Minimum number of character will be augmented.
+    :param int aug_char_max: Maximum number of character will be augmented. If None is passed, number of augmentation is
+        calculated via aup_char_p. If calculated result from aug_char_p is smaller than aug_char_max, will use calculated result
+        from aup_char_p. Otherwise, using aug_max.
+    :param float aug_char_p: Percentage of character (per token) will be augmented.
+    :param int aug_word_min: Minimum number of word will be augmented.
+    :param int aug_word_max: Maximum number of word will be augmented. If None is passed, number of augmentation is
+        calculated via aup_word_p. If calculated result from aug_word_p is smaller than aug_word_max, will use calculated result
+        from aug_word_p. Otherwise, using aug_max.
+    :param float aug_word_p: Percentage of word will be augmented.
+    :param int min_char: If word less than this value, do not draw word for augmentation
+    :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+    :param bool use_graphemes: Treat extended grapheme clusters (letters with combining accents, emoji sequences,
+        flags) as single chars, so they are never split. Best used with tokenizer='unicode'
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+
+    >>> import fasttextaug.augmenter.char as fac
+    >>> fac.ConfusionAug.train_and_save(['Hello world'], ['He1lo wor1d'], './confusion.json')
+    >>> aug = fac.ConfusionAug(model_path='./confusion.json')
+    """
+
+    def __init__(
+        self,
+        model_path,
+        aug_char_min=1,
+        aug_char_max=10,
+        aug_char_p=0.3,
+        aug_word_min=1,
+        aug_word_max=10,
+        aug_word_p=0.3,
+        min_char=1,
+        stopwords=None,
+        use_graphemes=False,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
+    ):
+        self._rust_aug = RustConfusionApiClass(
+            aug_min_char=aug_char_min,
+            aug_max_char=aug_char_max,
+            aug_p_char=aug_char_p,
+            aug_min_word=aug_word_min,
+            aug_max_word=aug_word_max,
+            aug_p_word=aug_word_p,
+            stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
+            min_char=min_char,
+            model_path=model_path,
+            use_graphemes=use_graphemes,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def get_rust_api_object(self) -> RustConfusionApiClass:
+        return self._rust_aug
+
+    @staticmethod
+    def train_and_save(clean: List[str], noisy: List[str], model_path: str, min_count: int = 1) -> None:
+        """
+        Align each pair of clean and noisy text at the character level (Levenshtein alignment),
+        count substitutions, insertions and deletions and save weighted model to model_path.
+        Confusions, met less than min_count times, are dropped
+        """
+        RustConfusionApiClass.train_and_save(clean, noisy, model_path, min_count)
@@ -0,0 +1,89 @@
+from fasttextaug.utils import get_lib_abspath
+from fasttextaug.rust_fasttextaug import RustHomoglyphApiClass
+
+from ..base import BaseAug
+
+
+class HomoglyphAug(BaseAug):
+    """
+    Augmenter that replace characters with visually confusable unicode characters. For example, Latin 'a' may be\
+        replaced with Cyrillic 'а'. Useful for adversarial robustness testing.
+
+    :param int aug_char_min: Minimum number of character will be augmented.
+    :param int aug_char_max: Maximum number of character will be augmented. If None is passed, number of augmentation is
+        calculated via aup_char_p. If calculated result from aug_char_p is smaller than aug_char_max, will use calculated result
+        from aup_char_p. Otherwise, using aug_max.
+    :param float aug_char_p: Percentage of character (per token) will be augmented.
+    :param int aug_word_min: Minimum number of word will be augmented.
+    :param int aug_word_max: Maximum number of word will be augmented. If None is passed, number of augmentation is
+        calculated via aup_word_p. If calculated result from aug_word_p is smaller than aug_word_max, will use calculated result
+        from aug_word_p. Otherwise, using aug_max.
+    :param float aug_word_p: Percentage of word will be augmented.
+    :param int min_char: If word less than this value, do not draw word for augmentation
+    :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+    :param obj dict_of_path: Use pre-defined dictionary by default. Pass either file path of dict to use custom mapping.
+    :param str lang: Indicate built-in confusables table. Default value is 'en'. Possible values are 'en', 'ru' (Russian).
+        If custom model is used (passing model_path), this value will be ignored.
+    :param bool use_graphemes: Treat extended grapheme clusters (letters with combining accents, emoji sequences,
+        flags) as single chars, so they are never split. Best used with tokenizer='unicode'
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+
+    >>> import fasttextaug.augmenter.char as fac
+    >>> aug = fac.HomoglyphAug()
+    """
+
+    def __init__(
+        self,
+        aug_char_min=2,
+        aug_char_max=10,
+        aug_char_p=0.3,
+        aug_word_min=1,
+        aug_word_max=10,
+        aug_word_p=0.3,
+        min_char=1,
+        stopwords=None,
+        dict_of_path=None,
+        lang=None,
+        use_graphemes=False,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
+    ):
+        if dict_of_path is None:
+            dir_path = get_lib_abspath() + "/res/homoglyph"
+            lang = "en" if lang is None else lang
+            dict_of_path = f"{dir_path}/{lang}.json"
+
+        self._rust_aug = RustHomoglyphApiClass(
+            aug_min_char=aug_char_min,
+            aug_max_char=aug_char_max,
+            aug_p_char=aug_char_p,
+            aug_min_word=aug_word_min,
+            aug_max_word=aug_word_max,
+            aug_p_word=aug_word_p,
+            stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
+            min_char=min_char,
+            dict_of_path=dict_of_path,
+            use_graphemes=use_graphemes,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def get_rust_api_object(self) -> RustHomoglyphApiClass:
+        return self._rust_aug
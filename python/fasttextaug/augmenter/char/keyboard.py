@@ -20,13 +20,35 @@ class KeyboardAug(BaseAug):
         from aug_word_p. Otherwise, using aug_max.
     :param float aug_word_p: Percentage of word will be augmented.
     :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
     :param bool include_special_char: Include special character
     :param bool include_numeric: If True, numeric character may be included in augmented data.
     :param bool include_upper_case: If True, upper case character may be included in augmented data.
     :param int min_char: If word less than this value, do not draw word for augmentation
-    :param str model_path: Loading customize model from file system
+    :param str model_path: Loading customize model from file system. Model may be weighted,
+        e.g. {"l": {"k": 0.7, "o": 0.3}}, then more probable typos are drawn more often.
     :param str lang: Indicate built-in language model. Default value is 'en'. Possible values are 'en', 'ru' (Russian).
         If custom model is used (passing model_path), this value will be ignored.
+    :param str action: Possible values are 'insert', 'substitute' and 'delete'. If value is 'insert', a character
+        from the model is inserted before the original one. If value is 'delete', a character, that exists in
+        the model, is removed.
+    :param str layout: Generate model from keyboard geometry instead of model_path. Either built-in layout name
+        ('qwerty', 'qwertz', 'azerty', 'dvorak', 'jcuken') or path to json-file with custom layout, e.g.
+        {"rows": [{"offset": 0.0, "keys": "`123", "shift_keys": "~!@#"}, ...]}
+    :param float max_distance: Keys within this distance (in key widths) are considered neighbours. Used with layout.
+    :param float distance_decay: If passed, closer keys are drawn more often, weight of neighbour is
+        exp(-distance_decay * distance). Used with layout.
+    :param bool use_graphemes: Treat extended grapheme clusters (letters with combining accents, emoji sequences,
+        flags) as single chars, so they are never split. Best used with tokenizer='unicode'
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
 
     >>> import fasttextaug.augmenter.char as fac
     >>> aug = fac.KeyboardAug()
@@ -47,6 +69,17 @@ class KeyboardAug(BaseAug):
         min_char=4,
         model_path=None,
         lang=None,
+        action="substitute",
+        layout=None,
+        max_distance=1.5,
+        distance_decay=None,
+        use_graphemes=False,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
     ):
         if model_path is None:
             dir_path = get_lib_abspath() + "/res/keyboard"
@@ -54,6 +87,7 @@ class KeyboardAug(BaseAug):
             model_path = f"{dir_path}/{lang}.json"
 
         self._rust_aug = RustKeyboardApiClass(
+            action=action,
             aug_min_char=aug_char_min,
             aug_max_char=aug_char_max,
             aug_p_char=aug_char_p,
@@ -61,11 +95,21 @@ class KeyboardAug(BaseAug):
             aug_max_word=aug_word_max,
             aug_p_word=aug_word_p,
             stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
             include_special_char=include_special_char,
             include_numeric=include_numeric,
             include_upper_case=include_upper_case,
             min_char=min_char,
             model_path=model_path,
+            layout=layout,
+            max_distance=max_distance,
+            distance_decay=distance_decay,
+            use_graphemes=use_graphemes,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
         )
 
     def get_rust_api_object(self) -> RustKeyboardApiClass:
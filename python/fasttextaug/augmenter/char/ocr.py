@@ -21,9 +21,25 @@ class OcrAug(BaseAug):
     :param float aug_word_p: Percentage of word will be augmented.
     :param int min_char: If word less than this value, do not draw word for augmentation
     :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
     :param obj dict_of_path: Use pre-defined dictionary by default. Pass either file path of dict to use custom mapping.
+        Mapping may be weighted, e.g. {"l": {"1": 0.7, "I": 0.3}}, then more probable errors are drawn more often.
     :param str lang: Indicate built-in language model. Default value is 'en'. Possible values are 'en', 'ru' (Russian).
         If custom model is used (passing model_path), this value will be ignored.
+    :param str action: Possible values are 'insert', 'substitute' and 'delete'. If value is 'insert', a character
+        from the model is inserted before the original one. If value is 'delete', a character, that exists in
+        the model, is removed.
+    :param bool use_graphemes: Treat extended grapheme clusters (letters with combining accents, emoji sequences,
+        flags) as single chars, so they are never split. Best used with tokenizer='unicode'
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
 
     >>> import fasttextaug.augmenter.char as fac
     >>> aug = fac.OcrAug()
@@ -41,6 +57,14 @@ class OcrAug(BaseAug):
         stopwords=None,
         dict_of_path=None,
         lang=None,
+        action="substitute",
+        use_graphemes=False,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
     ):
         if dict_of_path is None:
             dir_path = get_lib_abspath() + "/res/ocr"
@@ -48,6 +72,7 @@ class OcrAug(BaseAug):
             dict_of_path = f"{dir_path}/{lang}.json"
 
         self._rust_aug = RustOCRApiClass(
+            action=action,
             aug_min_char=aug_char_min,
             aug_max_char=aug_char_max,
             aug_p_char=aug_char_p,
@@ -55,8 +80,15 @@ class OcrAug(BaseAug):
             aug_max_word=aug_word_max,
             aug_p_word=aug_word_p,
             stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
             min_char=min_char,
             dict_of_path=dict_of_path,
+            use_graphemes=use_graphemes,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
         )
 
     def get_rust_api_object(self) -> RustOCRApiClass:
@@ -36,10 +36,22 @@ class RandomCharAug(BaseAug):
     :param str spec_char: Special character may be included in augmented data. If `candidates'
         value is provided, this param will be ignored.
     :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
     :param List candidates: List of string for augmentation. E.g. ['AAA', '11', '===']. If values is provided,
         `include_upper_case`, `include_lower_case`, `include_numeric` and `spec_char` will be ignored.
     :param str lang: Indicate built-in set of chars (uppercase / lowercase). Default value is 'en'. Possible values are 'en', 'ru' (Russian).
         If `candidates' value is provided, this param will be ignored.
+    :param bool use_graphemes: Treat extended grapheme clusters (letters with combining accents, emoji sequences,
+        flags) as single chars, so they are never split. Best used with tokenizer='unicode'
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
 
     >>> import fasttextaug.augmenter.char as fac
     >>> aug = fac.RandomCharAug()
@@ -64,6 +76,13 @@ class RandomCharAug(BaseAug):
         stopwords=None,
         candidates=None,
         lang=None,
+        use_graphemes=False,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
     ):
         if lang is None:
             lang = "en"
@@ -82,10 +101,17 @@ class RandomCharAug(BaseAug):
             include_special_char=include_special_char,
             lang=lang,
             stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
             min_char=min_char,
             swap_mode=swap_mode,
             spec_char=spec_char,
             candidates=candidates,
+            use_graphemes=use_graphemes,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
         )
 
     def get_rust_api_object(self) -> RustRandomCharApiClass:
@@ -1 +1,9 @@
 from .random_word import RandomWordAug
+from .spelling import SpellingAug
+from .synonym import SynonymAug
+from .antonym import AntonymAug
+from .split import SplitAug
+from .reserved import ReservedAug
+from .tfidf import TfIdfAug
+from .word_embs import WordEmbsAug
+from .layout_switch import LayoutSwitchAug
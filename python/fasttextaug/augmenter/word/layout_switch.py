@@ -0,0 +1,77 @@
+from fasttextaug.rust_fasttextaug import RustLayoutSwitchApiClass
+
+from ..base import BaseAug
+
+
+class LayoutSwitchAug(BaseAug):
+    """
+    Augmenter that simulate text typed with wrong keyboard layout, e.g. 'привет' -> 'ghbdtn'.
+        Characters are converted to the characters on the same keys of other keyboard layout.
+
+    :param str mode: 'word' or 'suffix'. If value is 'word', whole sampled word is converted.
+        If value is 'suffix', only random ending of word is converted (layout was switched in the middle of typing)
+    :param bool reverse: Convert from target_layout to source_layout. With default layouts True means
+        Russian text typed with English layout, False - English text typed with Russian layout
+    :param int aug_min: Minimum number of word will be augmented.
+    :param int aug_max: Maximum number of word will be augmented. If None is passed, number of augmentation is
+        calculated via aup_p. If calculated result from aug_p is smaller than aug_max, will use calculated result from
+        aug_p. Otherwise, using aug_max.
+    :param float aug_p: Percentage of word will be augmented.
+    :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+    :param int min_char: If word less than this value, do not draw word for augmentation
+    :param str source_layout: Layout, whose characters are converted: name of built-in layout ('qwerty', 'qwertz',
+        'azerty', 'dvorak', 'jcuken') or path to json-file with custom layout, same as layout of KeyboardAug
+    :param str target_layout: Layout, characters are converted to. Built-in layout name or path to json-file
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+
+    >>> import fasttextaug.augmenter.word as faw
+    >>> aug = faw.LayoutSwitchAug()
+    """
+
+    def __init__(
+        self,
+        mode="word",
+        reverse=True,
+        aug_min=1,
+        aug_max=10,
+        aug_p=0.3,
+        stopwords=None,
+        min_char=None,
+        source_layout="qwerty",
+        target_layout="jcuken",
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
+    ):
+        self._rust_aug = RustLayoutSwitchApiClass(
+            aug_min_word=aug_min,
+            aug_max_word=aug_max,
+            aug_p_word=aug_p,
+            stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
+            min_char=min_char,
+            mode=mode,
+            reverse=reverse,
+            source_layout=source_layout,
+            target_layout=target_layout,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def get_rust_api_object(self) -> RustLayoutSwitchApiClass:
+        return self._rust_aug
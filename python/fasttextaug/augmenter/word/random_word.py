@@ -7,7 +7,8 @@ class RandomWordAug(BaseAug):
     """
     Augmenter that apply randomly behavior for augmentation.
 
-    :param str action: 'substitute', 'swap' or 'delete'. If value is 'swap', adjacent words will be swapped randomly.
+    :param str action: 'insert', 'substitute', 'swap' or 'delete'. If value is 'insert', word from target_words
+        will be inserted after sampled word. If value is 'swap', adjacent words will be swapped randomly.
         If value is 'delete', word will be removed randomly.
     :param int aug_min: Minimum number of word will be augmented.
     :param int aug_max: Maximum number of word will be augmented. If None is passed, number of augmentation is
@@ -15,11 +16,22 @@ class RandomWordAug(BaseAug):
         aug_p. Otherwise, using aug_max.
     :param float aug_p: Percentage of word will be augmented.
     :param Set stopwords: Set of words which will be skipped from augment operation
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
     :param list target_words: Union[List[str], Dict[str, List[str]]]
-        - List of word for replacement (used for substitute operation only). Default value is _.
+        - List of word for replacement (used for substitute and insert operations only). Default value is _.
         Each word for augmentation will be substituted by a random one from target_words
+        (or random one from target_words will be inserted after it)
         - Dict[str, List[str]]. Each word (key) for augmentation will be substituted
-        by a random one from target_words[key]
+        by a random one from target_words[key] (or random one from target_words[key] will be inserted after it)
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
 
     >>> import fasttextaug.augmenter.word as faw
     >>> aug = faw.RandomWordAug()
@@ -33,6 +45,12 @@ class RandomWordAug(BaseAug):
         aug_p=0.3,
         stopwords=None,
         target_words=None,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
     ):
         target_vec_words = None
         target_map_words = None
@@ -49,8 +67,14 @@ class RandomWordAug(BaseAug):
             aug_max_word=aug_max,
             aug_p_word=aug_p,
             stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
             target_vec_words=target_vec_words,
             target_map_words=target_map_words,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
         )
 
     def get_rust_api_object(self) -> RustRandomWordApiClass:
@@ -0,0 +1,65 @@
+from fasttextaug.rust_fasttextaug import RustReservedApiClass
+
+from ..base import BaseAug
+
+
+class ReservedAug(BaseAug):
+    """
+    Augmenter that replace reserved words (or phrases) with other variants from the same group.
+
+    :param list reserved_tokens: A list of groups of interchangeable terms. Terms can contain several words.
+        E.g. [['FW', 'Fwd', 'Forward'], ['New York', 'NYC']]
+    :param bool case_sensitive: If False, terms are matched ignoring case.
+    :param int aug_min: Minimum number of term will be augmented.
+    :param int aug_max: Maximum number of term will be augmented. If None is passed, number of augmentation is
+        calculated via aup_p. If calculated result from aug_p is smaller than aug_max, will use calculated result from
+        aug_p. Otherwise, using aug_max.
+    :param float aug_p: Percentage of term will be augmented.
+    :param set stopwords: Set of terms which will be skipped from augment operation.
+    :param str stopwords_mode: How terms are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole term)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+
+    >>> import fasttextaug.augmenter.word as faw
+    >>> aug = faw.ReservedAug(reserved_tokens=[['FW', 'Fwd', 'Forward'], ['New York', 'NYC']])
+    """
+
+    def __init__(
+        self,
+        reserved_tokens,
+        case_sensitive=True,
+        aug_min=1,
+        aug_max=10,
+        aug_p=0.3,
+        stopwords=None,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
+    ):
+        self._rust_aug = RustReservedApiClass(
+            aug_min_word=aug_min,
+            aug_max_word=aug_max,
+            aug_p_word=aug_p,
+            stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
+            reserved_tokens=reserved_tokens,
+            case_sensitive=case_sensitive,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def get_rust_api_object(self) -> RustReservedApiClass:
+        return self._rust_aug
@@ -0,0 +1,87 @@
+from typing import List, Optional
+
+from fasttextaug.rust_fasttextaug import RustTfIdfApiClass
+
+from ..base import BaseAug
+
+
+class TfIdfAug(BaseAug):
+    """
+    Augmenter that leverage TF-IDF statistics to insert or substitute word.
+    Words with low TF-IDF score (uninformative) are substituted more often,
+    replacements are drawn from top_k words with the highest score, weighted by score.
+
+    :param str model_path: Path of TF-IDF model, created by `TfIdfAug.train_and_save`
+    :param str action: Either 'insert or 'substitute'. If value is 'insert', a new word will be injected to random
+        position. If value is 'substitute', word will be replaced according to TF-IDF calculation
+    :param int top_k: Controlling lucky draw pool. Top k score token will be used for augmentation.
+    :param int aug_min: Minimum number of word will be augmented.
+    :param int aug_max: Maximum number of word will be augmented. If None is passed, number of augmentation is
+        calculated via aup_p. If calculated result from aug_p is smaller than aug_max, will use calculated result from
+        aug_p. Otherwise, using aug_max.
+    :param float aug_p: Percentage of word will be augmented.
+    :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+    :param int min_char: If word less than this value, do not draw word for augmentation
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+
+    >>> import fasttextaug.augmenter.word as faw
+    >>> faw.TfIdfAug.train_and_save(['The quick brown fox', 'The lazy dog'], './tfidf.json')
+    >>> aug = faw.TfIdfAug(model_path='./tfidf.json')
+    """
+
+    def __init__(
+        self,
+        model_path,
+        action="substitute",
+        top_k=5,
+        aug_min=1,
+        aug_max=10,
+        aug_p=0.3,
+        stopwords=None,
+        min_char=None,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
+    ):
+        self._rust_aug = RustTfIdfApiClass(
+            action=action,
+            aug_min_word=aug_min,
+            aug_max_word=aug_max,
+            aug_p_word=aug_p,
+            stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
+            min_char=min_char,
+            top_k=top_k,
+            model_path=model_path,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def get_rust_api_object(self) -> RustTfIdfApiClass:
+        return self._rust_aug
+
+    @staticmethod
+    def train_and_save(
+        corpus: List[str], model_path: str, tokenizer: str = "alphanumeric", token_pattern: Optional[str] = None
+    ) -> None:
+        """
+        Train TF-IDF model on corpus (each element is a separate document) and save it to model_path.
+        Text is tokenized the same way as during augmentation, so pass the same `tokenizer` and
+        `token_pattern`, the augmenter is created with
+        """
+        RustTfIdfApiClass.train_and_save(corpus, model_path, tokenizer, token_pattern)
@@ -0,0 +1,74 @@
+from fasttextaug.rust_fasttextaug import RustWordEmbsApiClass
+
+from ..base import BaseAug
+
+
+class WordEmbsAug(BaseAug):
+    """
+    Augmenter that substitute word by its nearest neighbours (cosine similarity) in word embeddings space.
+
+    :param str model_path: Path of pretrained vectors. word2vec binary ('.bin'), GloVe text and
+        fastText text ('.vec') files are supported
+    :param str model_type: 'word2vec', 'glove' or 'fasttext'. If None is passed, type is detected by file extension
+    :param int top_k: Controlling lucky draw pool. Top k nearest neighbours will be used for augmentation.
+    :param int max_num_vocab: If set, only first N words of file are loaded
+    :param int aug_min: Minimum number of word will be augmented.
+    :param int aug_max: Maximum number of word will be augmented. If None is passed, number of augmentation is
+        calculated via aup_p. If calculated result from aug_p is smaller than aug_max, will use calculated result from
+        aug_p. Otherwise, using aug_max.
+    :param float aug_p: Percentage of word will be augmented.
+    :param set stopwords: Set of words which will be skipped from augment operation.
+    :param str stopwords_mode: How words are compared with stopwords: 'exact' (default), 'case_insensitive',
+        'casefold' (Unicode case folding), 'regex' or 'glob' (stopwords are patterns, matching the whole word)
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+    :param int min_char: If word less than this value, do not draw word for augmentation
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default, every continuous sequence of
+        alphanumeric chars is a word), 'whitespace', 'regex' or 'unicode' (UAX#29 word boundaries)
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'. If None is passed,
+        default pattern keeps together words like "don't", "e-mail", "U.S.", "COVID-19"
+    :param list protected: Names of built-in detectors, whose matches are never augmented:
+        'url', 'email', 'number', 'mention', 'hashtag', 'placeholder' (like {name} or {{name}})
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+
+    >>> import fasttextaug.augmenter.word as faw
+    >>> aug = faw.WordEmbsAug(model_path='./GoogleNews-vectors-negative300.bin', max_num_vocab=100000)
+    """
+
+    def __init__(
+        self,
+        model_path,
+        model_type=None,
+        top_k=100,
+        max_num_vocab=None,
+        aug_min=1,
+        aug_max=10,
+        aug_p=0.3,
+        stopwords=None,
+        min_char=None,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        stopwords_mode="exact",
+        seed=None,
+    ):
+        self._rust_aug = RustWordEmbsApiClass(
+            aug_min_word=aug_min,
+            aug_max_word=aug_max,
+            aug_p_word=aug_p,
+            stopwords=stopwords,
+            stopwords_mode=stopwords_mode,
+            min_char=min_char,
+            model_type=model_type,
+            model_path=model_path,
+            top_k=top_k,
+            max_num_vocab=max_num_vocab,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def get_rust_api_object(self) -> RustWordEmbsApiClass:
+        return self._rust_aug
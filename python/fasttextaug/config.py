@@ -0,0 +1,60 @@
+from pathlib import Path
+from typing import Union
+
+from fasttextaug.rust_fasttextaug import load_config as rust_load_config
+
+from .augmenter import char, word
+from .augmenter.base import BaseAug
+from .flow.pipeline import Pipeline
+
+_AUGMENTERS = {
+    "RustOCRApiClass": char.OcrAug,
+    "RustKeyboardApiClass": char.KeyboardAug,
+    "RustRandomCharApiClass": char.RandomCharAug,
+    "RustHomoglyphApiClass": char.HomoglyphAug,
+    "RustConfusionApiClass": char.ConfusionAug,
+    "RustLayoutSwitchApiClass": word.LayoutSwitchAug,
+    "RustRandomWordApiClass": word.RandomWordAug,
+    "RustSpellingApiClass": word.SpellingAug,
+    "RustSynonymApiClass": word.SynonymAug,
+    "RustAntonymApiClass": word.AntonymAug,
+    "RustSplitApiClass": word.SplitAug,
+    "RustReservedApiClass": word.ReservedAug,
+    "RustTfIdfApiClass": word.TfIdfAug,
+    "RustWordEmbsApiClass": word.WordEmbsAug,
+    "RustPipelineApiClass": Pipeline,
+}
+
+
+def _format_of(path: Path) -> str:
+    return "toml" if path.suffix == ".toml" else "json"
+
+
+def from_config(config: str, format: str = "json") -> BaseAug:
+    """
+    Create augmenter (or pipeline) from config string in `format`: 'json' or 'toml'.
+
+    >>> aug = from_config('{"type": "split", "aug_min_word": 1, "aug_max_word": 10, "aug_p_word": 0.3}')
+    """
+    rust_aug = rust_load_config(config, format)
+    aug_class = _AUGMENTERS[type(rust_aug).__name__]
+    aug = aug_class.__new__(aug_class)
+    aug._rust_aug = rust_aug
+    return aug
+
+
+def load_config(path: Union[str, Path]) -> BaseAug:
+    """
+    Create augmenter (or pipeline) from config file, files with '.toml' suffix are read as TOML, others as JSON.
+    """
+    path = Path(path)
+    return from_config(path.read_text(encoding="utf-8"), _format_of(path))
+
+
+def save_config(aug: BaseAug, path: Union[str, Path]):
+    """
+    Save config of augmenter (or pipeline) to file, format is chosen by suffix as in `load_config`.
+    Config contains seed of augmenter, so augmenter loaded from it reproduces the same results.
+    """
+    path = Path(path)
+    path.write_text(aug.get_config(_format_of(path)), encoding="utf-8")
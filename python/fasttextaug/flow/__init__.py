@@ -0,0 +1 @@
+from .pipeline import Sequential, Sometimes, OneOf
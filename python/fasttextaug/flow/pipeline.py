@@ -0,0 +1,147 @@
+from typing import List, Optional
+
+from fasttextaug.rust_fasttextaug import RustPipelineApiClass
+
+from ..augmenter.base import BaseAug
+
+
+class Pipeline(BaseAug):
+    """
+    Base class of pipelines. Augmenters are applied to the same text in Rust:
+    it's tokenized once and every next augmenter works on top of the previous one.
+    Augmenters must have the same tokenizer and protected spans as pipeline, ValueError is raised otherwise.
+    """
+
+    def __init__(
+        self,
+        augmenters: List[BaseAug],
+        mode: str,
+        weights: Optional[List[float]],
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        seed=None,
+    ):
+        self._rust_aug = RustPipelineApiClass(
+            steps=[aug.get_rust_api_object() for aug in augmenters],
+            mode=mode,
+            weights=weights,
+            tokenizer=tokenizer,
+            token_pattern=token_pattern,
+            protected_detectors=protected,
+            protected_patterns=protected_patterns,
+            seed=seed,
+        )
+
+    def augment_with_changed_count(self, data: List[str], seed=None):
+        """
+        Augment list of values, for each value also return total number of changes made by all augmenters
+        """
+        return self.get_rust_api_object().augment_list_with_changed_count(data, seed)
+
+    def get_rust_api_object(self) -> RustPipelineApiClass:
+        return self._rust_aug
+
+
+class Sequential(Pipeline):
+    """
+    Apply augmenters one after another.
+
+    :param list augmenters: Augmenters (or other pipelines) to apply
+    :param list aug_p: Probability to apply each augmenter. If None is passed, every augmenter is applied
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default), 'whitespace', 'regex' or 'unicode'
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'
+    :param list protected: Names of built-in detectors, whose matches are never augmented
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+
+    >>> import fasttextaug.augmenter.word as faw
+    >>> import fasttextaug.flow as faf
+    >>> aug = faf.Sequential([faw.SplitAug(), faw.RandomWordAug(action="swap")])
+    """
+
+    def __init__(
+        self,
+        augmenters: List[BaseAug],
+        aug_p: Optional[List[float]] = None,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        seed=None,
+    ):
+        super().__init__(
+            augmenters, "sequential", aug_p, tokenizer, token_pattern, protected, protected_patterns, seed
+        )
+
+
+class Sometimes(Pipeline):
+    """
+    Apply augmenters one after another, each of them with probability `aug_p`.
+
+    :param list augmenters: Augmenters (or other pipelines) to apply
+    :param float aug_p: Probability to apply each augmenter
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default), 'whitespace', 'regex' or 'unicode'
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'
+    :param list protected: Names of built-in detectors, whose matches are never augmented
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+
+    >>> import fasttextaug.augmenter.char as fac
+    >>> import fasttextaug.flow as faf
+    >>> aug = faf.Sometimes([fac.KeyboardAug(), fac.OcrAug()], aug_p=0.5)
+    """
+
+    def __init__(
+        self,
+        augmenters: List[BaseAug],
+        aug_p=0.5,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        seed=None,
+    ):
+        super().__init__(
+            augmenters,
+            "sequential",
+            [aug_p] * len(augmenters),
+            tokenizer,
+            token_pattern,
+            protected,
+            protected_patterns,
+            seed,
+        )
+
+
+class OneOf(Pipeline):
+    """
+    Apply one of augmenters, drawn according to its weight.
+
+    :param list augmenters: Augmenters (or other pipelines) to choose from
+    :param list weights: Weight of each augmenter. If None is passed, all augmenters are equally likely
+    :param str tokenizer: How to split text to tokens: 'alphanumeric' (default), 'whitespace', 'regex' or 'unicode'
+    :param str token_pattern: Regex for word tokens, used only if tokenizer='regex'
+    :param list protected: Names of built-in detectors, whose matches are never augmented
+    :param list protected_patterns: Custom regexes, whose matches are never augmented
+    :param int seed: Seed of random generator, makes sequence of augment calls reproducible
+
+    >>> import fasttextaug.augmenter.char as fac
+    >>> import fasttextaug.flow as faf
+    >>> aug = faf.OneOf([fac.KeyboardAug(), fac.OcrAug()], weights=[3, 1])
+    """
+
+    def __init__(
+        self,
+        augmenters: List[BaseAug],
+        weights: Optional[List[float]] = None,
+        tokenizer="alphanumeric",
+        token_pattern=None,
+        protected=None,
+        protected_patterns=None,
+        seed=None,
+    ):
+        super().__init__(
+            augmenters, "one_of", weights, tokenizer, token_pattern, protected, protected_patterns, seed
+        )
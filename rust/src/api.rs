@@ -1,11 +1,42 @@
+mod antonym;
 mod base;
+mod config;
+mod confusion;
+mod homoglyph;
 mod keyboard;
+mod layout_switch;
 mod ocr;
+mod pipeline;
 mod random_char;
 mod random_word;
+mod reserved;
+mod spelling;
+mod split;
+mod synonym;
+mod tfidf;
+mod word_embs;
 
-pub use base::RustBaseApiClass;
-pub use keyboard::RustKeyboardApiClass;
-pub use ocr::RustOCRApiClass;
-pub use random_char::RustRandomCharApiClass;
-pub use random_word::RustRandomWordApiClass;
+pub use antonym::{AntonymConfig, RustAntonymApiClass};
+#[cfg(feature = "python")]
+pub use base::value_error;
+pub use base::{
+    create_action, create_doc, create_keyboard_layout, create_protected_spans, create_stopwords,
+    create_tokenizer, ApiResult, DynApiClass, LabeledTokens, RustBaseApiClass, SeedSource,
+};
+#[cfg(feature = "python")]
+pub use config::load_config;
+pub use config::{AugmenterConfig, ConfigFormat};
+pub use confusion::{ConfusionConfig, RustConfusionApiClass};
+pub use homoglyph::{HomoglyphConfig, RustHomoglyphApiClass};
+pub use keyboard::{KeyboardConfig, RustKeyboardApiClass};
+pub use layout_switch::{LayoutSwitchConfig, RustLayoutSwitchApiClass};
+pub use ocr::{OcrConfig, RustOCRApiClass};
+pub use pipeline::{PipelineConfig, RustPipelineApiClass};
+pub use random_char::{RandomCharConfig, RustRandomCharApiClass};
+pub use random_word::{RandomWordConfig, RustRandomWordApiClass};
+pub use reserved::{ReservedConfig, RustReservedApiClass};
+pub use spelling::{RustSpellingApiClass, SpellingConfig};
+pub use split::{RustSplitApiClass, SplitConfig};
+pub use synonym::{RustSynonymApiClass, SynonymConfig};
+pub use tfidf::{RustTfIdfApiClass, TfIdfConfig};
+pub use word_embs::{RustWordEmbsApiClass, WordEmbsConfig};
@@ -1,11 +1,139 @@
-use crate::aug::BaseAugmentor;
-use crate::doc::Doc;
+use crate::aug::{Action, BaseAugmentor, Stopwords, StopwordsMode};
+use crate::doc::{tokenizer_from_name, Doc, ProtectedSpans, SpanAlignment, Tokenizer};
+use crate::model::character::KeyboardLayout;
 use crate::model::BaseModel;
 use crate::utils;
-use rand::{rngs::StdRng, SeedableRng};
-use std::sync::Arc;
+#[cfg(feature = "python")]
+use pyo3::exceptions::PyValueError;
+#[cfg(feature = "python")]
+use pyo3::PyErr;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
 use std::thread::JoinHandle;
 
+/// Pre-tokenized input: (tokens, label per token)
+pub type LabeledTokens = (Vec<String>, Vec<String>);
+
+/// Result of creating augmenter: error is invalid parameter or model file, that can't be read
+pub type ApiResult<T> = Result<T, Box<dyn Error>>;
+
+/// Errors of augmenters are ValueError on python side
+#[cfg(feature = "python")]
+pub fn value_error(err: Box<dyn Error>) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// Create tokenizer by name, passed from python side
+///
+/// Returns error on unknown name or invalid regex pattern
+pub fn create_tokenizer(
+    tokenizer: &str,
+    token_pattern: Option<String>,
+) -> ApiResult<Arc<dyn Tokenizer>> {
+    tokenizer_from_name(tokenizer, token_pattern.as_deref())
+}
+
+/// Create action by name, passed from python side
+///
+/// Returns error on unknown action or action, that isn't in `supported`
+pub fn create_action(action: &str, supported: &[Action]) -> ApiResult<Action> {
+    match Action::from_name(action) {
+        Some(parsed) if supported.contains(&parsed) => Ok(parsed),
+        _ => {
+            let names: Vec<&str> = supported.iter().map(Action::name).collect();
+            Err(format!(
+                "unsupported action '{}', expected one of: {}",
+                action,
+                names.join(", ")
+            )
+            .into())
+        }
+    }
+}
+
+/// Create protected spans from names of built-in detectors and custom regexes
+///
+/// Returns None, if nothing is protected. Returns error on unknown detector or invalid regex
+pub fn create_protected_spans(
+    detectors: Option<Vec<String>>,
+    patterns: Option<Vec<String>>,
+) -> ApiResult<Option<ProtectedSpans>> {
+    let detectors = detectors.unwrap_or_default();
+    let patterns = patterns.unwrap_or_default();
+    if detectors.is_empty() && patterns.is_empty() {
+        return Ok(None);
+    }
+    ProtectedSpans::new(&detectors, &patterns).map(Some)
+}
+
+/// Create stopwords, compared with tokens according to `stopwords_mode`:
+/// 'exact', 'case_insensitive', 'casefold', 'regex' or 'glob'
+///
+/// Returns error on unknown mode or invalid pattern
+pub fn create_stopwords(
+    stopwords: Option<HashSet<String>>,
+    stopwords_mode: &str,
+) -> ApiResult<Option<Stopwords>> {
+    let mode = StopwordsMode::from_name(stopwords_mode)
+        .ok_or_else(|| format!("unknown stopwords mode '{}'", stopwords_mode))?;
+    match stopwords {
+        Some(stopwords) => Stopwords::new(stopwords, mode).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Create keyboard layout: built-in one by name or custom one from json-file by path
+///
+/// Returns error with the path, if custom layout can't be read
+pub fn create_keyboard_layout(layout: &str) -> ApiResult<KeyboardLayout> {
+    KeyboardLayout::from_name_or_file(layout).map_err(|err| format!("{}: {}", layout, err).into())
+}
+
+/// Create Doc with passed tokenizer and mark tokens inside of protected spans
+pub fn create_doc(
+    input: &str,
+    tokenizer: &dyn Tokenizer,
+    protected_spans: &Option<ProtectedSpans>,
+) -> Doc {
+    let mut doc = Doc::new_with_tokenizer(input, tokenizer);
+    if let Some(protected_spans) = protected_spans {
+        doc.protect_spans(protected_spans);
+    }
+    doc
+}
+
+/// Source of base seeds for augmentation calls
+///
+/// The whole sequence of calls is reproducible from its seed
+pub struct SeedSource {
+    seed: u64,
+    rng: Mutex<StdRng>,
+}
+
+impl SeedSource {
+    /// If `seed` is None, it's drawn at random (and can be read back with `seed()`)
+    pub fn new(seed: Option<u64>) -> Self {
+        // Drawn seed fits into TOML integer, so config with it can be saved in any format
+        let seed = seed.unwrap_or_else(|| rand::thread_rng().gen_range(0..=i64::MAX as u64));
+        Self {
+            seed,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+
+    /// Seed, the source was created with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Base seed for the next call: `seed` of call, if it's passed, otherwise draw new one
+    pub fn next_seed(&self, seed: Option<u64>) -> u64 {
+        seed.unwrap_or_else(|| self.rng.lock().unwrap().gen())
+    }
+}
+
 /// Base RustApiClass functionality
 pub trait RustBaseApiClass<A, M>
 where
@@ -14,25 +142,46 @@ where
 {
     /// create specific augmentor instanse
     fn create_augmentor_instance(&self) -> A;
+    /// Tokenizer, that splits input to Doc
+    fn get_tokenizer(&self) -> Arc<dyn Tokenizer>;
+    /// Spans of input, that are never augmented
+    fn get_protected_spans(&self) -> Arc<Option<ProtectedSpans>>;
+    /// Source of base seeds, created from `seed` of class
+    fn get_seed_source(&self) -> &SeedSource;
+    /// Augment input `n_on_thread` times, `first_idx` - index of first augmentation (to derive its RNG)
     fn create_thread_handle_string(
         &self,
         input_string_ref: Arc<String>,
         n_on_thread: usize,
+        first_idx: usize,
+        seed: u64,
     ) -> JoinHandle<Vec<String>>;
+    /// Augment values of list in range [left_idx, right_idx)
     fn create_thread_handle_list(
         &self,
         input_list_ref: Arc<Vec<String>>,
         left_idx: usize,
         right_idx: usize,
+        seed: u64,
     ) -> JoinHandle<Vec<String>>;
 
     /// Augment `input_string` `n` times in single thread mode
-    fn augment_string_single_thread(&self, input_string: String, n: usize) -> Vec<String> {
-        let mut rng: StdRng = SeedableRng::from_entropy();
+    ///
+    /// Every augmentation gets its own RNG, derived from `seed`
+    fn augment_string_single_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        let seed = self.get_seed_source().next_seed(seed);
         let mut result = Vec::with_capacity(n);
-        let mut doc = Doc::new(&input_string);
+        let tokenizer = self.get_tokenizer();
+        let protected_spans = self.get_protected_spans();
+        let mut doc = create_doc(&input_string, tokenizer.as_ref(), &protected_spans);
         let augmentor = self.create_augmentor_instance();
-        for _ in 0..n {
+        for idx in 0..n {
+            let mut rng = utils::item_rng(seed, idx);
             augmentor.augment(&mut doc, &mut rng);
             result.push(doc.get_augmented_string());
             doc.set_to_original();
@@ -41,22 +190,33 @@ where
     }
 
     /// Augment `input_string` `n` times in multi thread mode (`n_threads`)
+    ///
+    /// Result is the same as in single thread mode with the same `seed`
     fn augment_string_multi_thread(
         &self,
         input_string: String,
         n: usize,
         n_threads: usize,
+        seed: Option<u64>,
     ) -> Vec<String> {
+        let seed = self.get_seed_source().next_seed(seed);
         let mut result = Vec::with_capacity(n);
         let arc_input_string = Arc::new(input_string);
         let mut thread_handles = Vec::with_capacity(n_threads);
         let n_on_threads = utils::split_n_to_chunks(n, n_threads);
+        let mut first_idx = 0;
 
         for idx in 0..n_threads {
             let n_on_thread = n_on_threads[idx];
             let arc_input_string_ref = Arc::clone(&arc_input_string);
-            let handle = self.create_thread_handle_string(arc_input_string_ref, n_on_thread);
+            let handle = self.create_thread_handle_string(
+                arc_input_string_ref,
+                n_on_thread,
+                first_idx,
+                seed,
+            );
             thread_handles.push(handle);
+            first_idx += n_on_thread;
         }
         for thread_handle in thread_handles {
             let thread_res = thread_handle.join().unwrap();
@@ -66,20 +226,117 @@ where
     }
 
     /// Augment list of values in single thread mode
-    fn augment_list_single_thread(&self, input_list: Vec<String>) -> Vec<String> {
-        let mut rng: StdRng = SeedableRng::from_entropy();
+    fn augment_list_single_thread(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        let seed = self.get_seed_source().next_seed(seed);
         let mut result = Vec::with_capacity(input_list.len());
         let augmentor = self.create_augmentor_instance();
-        for input_str in input_list {
-            let mut doc = Doc::new(&input_str);
+        let tokenizer = self.get_tokenizer();
+        let protected_spans = self.get_protected_spans();
+        for (idx, input_str) in input_list.into_iter().enumerate() {
+            let mut rng = utils::item_rng(seed, idx);
+            let mut doc = create_doc(&input_str, tokenizer.as_ref(), &protected_spans);
             augmentor.augment(&mut doc, &mut rng);
             result.push(doc.get_augmented_string());
         }
         result
     }
 
+    /// Augment list of values in single thread mode
+    ///
+    /// Returns pairs (augmented value, number of changes made in it)
+    fn augment_list_with_changed_count(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, usize)> {
+        let seed = self.get_seed_source().next_seed(seed);
+        let mut result = Vec::with_capacity(input_list.len());
+        let augmentor = self.create_augmentor_instance();
+        let tokenizer = self.get_tokenizer();
+        let protected_spans = self.get_protected_spans();
+        for (idx, input_str) in input_list.into_iter().enumerate() {
+            let mut rng = utils::item_rng(seed, idx);
+            let mut doc = create_doc(&input_str, tokenizer.as_ref(), &protected_spans);
+            augmentor.augment(&mut doc, &mut rng);
+            result.push((doc.get_augmented_string(), doc.get_changed_count()));
+        }
+        result
+    }
+
+    /// Augment list of values in single thread mode
+    ///
+    /// Returns pairs (augmented value, char spans of its tokens: original -> augmented)
+    fn augment_with_alignment(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, Vec<SpanAlignment>)> {
+        let seed = self.get_seed_source().next_seed(seed);
+        let mut result = Vec::with_capacity(input_list.len());
+        let augmentor = self.create_augmentor_instance();
+        let tokenizer = self.get_tokenizer();
+        let protected_spans = self.get_protected_spans();
+        for (idx, input_str) in input_list.into_iter().enumerate() {
+            let mut rng = utils::item_rng(seed, idx);
+            let mut doc = create_doc(&input_str, tokenizer.as_ref(), &protected_spans);
+            augmentor.augment(&mut doc, &mut rng);
+            result.push((doc.get_augmented_string(), doc.get_alignment()));
+        }
+        result
+    }
+
+    /// Augment pre-tokenized labeled inputs in single thread mode
+    ///
+    /// Labels are carried over augmentation, inserted tokens get `default_label`.
+    /// Returns error, if number of tokens and labels differ
+    /// or token is empty or contains whitespace: its boundaries can't be restored
+    fn augment_labeled(
+        &self,
+        inputs: Vec<LabeledTokens>,
+        default_label: String,
+        seed: Option<u64>,
+    ) -> ApiResult<Vec<LabeledTokens>> {
+        let seed = self.get_seed_source().next_seed(seed);
+        let mut result = Vec::with_capacity(inputs.len());
+        let augmentor = self.create_augmentor_instance();
+        let protected_spans = self.get_protected_spans();
+        for (idx, (tokens, labels)) in inputs.into_iter().enumerate() {
+            if tokens.len() != labels.len() {
+                return Err(
+                    format!("got {} tokens and {} labels", tokens.len(), labels.len()).into(),
+                );
+            }
+            if let Some(token) = tokens
+                .iter()
+                .find(|token| token.is_empty() || token.chars().any(char::is_whitespace))
+            {
+                return Err(format!("token {:?} is empty or contains whitespace", token).into());
+            }
+            let mut doc = Doc::from_labeled_tokens(&tokens, &labels);
+            if let Some(protected_spans) = protected_spans.as_ref() {
+                doc.protect_spans(protected_spans);
+            }
+            let mut rng = utils::item_rng(seed, idx);
+            augmentor.augment(&mut doc, &mut rng);
+            result.push(doc.get_labeled_tokens(&default_label).into_iter().unzip());
+        }
+        Ok(result)
+    }
+
     /// Augment list of values in multi thread mode (`n_threads`)
-    fn augment_list_multi_thread(&self, input_list: Vec<String>, n_threads: usize) -> Vec<String> {
+    ///
+    /// Result is the same as in single thread mode with the same `seed`
+    fn augment_list_multi_thread(
+        &self,
+        input_list: Vec<String>,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        let seed = self.get_seed_source().next_seed(seed);
         let mut result = Vec::with_capacity(input_list.len());
         let arc_input_list = Arc::new(input_list);
         let chunk_indexes = utils::split_to_chunks_indexes(arc_input_list.len(), n_threads);
@@ -90,7 +347,7 @@ where
             if left_idx != right_idx {
                 let arc_input_list_ref = Arc::clone(&arc_input_list);
                 let handle =
-                    self.create_thread_handle_list(arc_input_list_ref, left_idx, right_idx);
+                    self.create_thread_handle_list(arc_input_list_ref, left_idx, right_idx, seed);
                 thread_handles.push(handle);
             }
         }
@@ -102,3 +359,99 @@ where
         result
     }
 }
+
+/// Augmentation of Rust*ApiClass without types of its augmentor and model,
+/// so any augmenter, created from config, can be used in the same place (e.g. in CLI)
+pub trait DynApiClass {
+    /// Augment list of values, in multi thread mode if `n_threads` > 1
+    fn augment_list(
+        &self,
+        input_list: Vec<String>,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String>;
+
+    /// Draw base seed for the next call, as `augment_list` does, if no `seed` is passed
+    fn next_seed(&self) -> u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{AugmenterConfig, ConfigFormat, RandomWordConfig, RustRandomWordApiClass};
+
+    #[test]
+    fn test_create_action() {
+        let supported = [Action::Substitute, Action::Swap];
+        assert_eq!(create_action("swap", &supported).unwrap(), Action::Swap);
+        assert!(create_action("split", &supported).is_err());
+        let err = create_action("substitue", &supported).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unsupported action 'substitue', expected one of: substitute, swap"
+        );
+    }
+
+    #[test]
+    fn test_labeled_token_boundaries() {
+        let config: RandomWordConfig =
+            serde_json::from_str(r#"{"action": "swap", "aug_p_word": 0.3, "seed": 1}"#).unwrap();
+        let api_object = RustRandomWordApiClass::from_config(config).unwrap();
+        let labeled = |tokens: &[&str]| {
+            let tokens: Vec<String> = tokens.iter().map(|x| x.to_string()).collect();
+            let labels = vec![String::from("B-LOC"); tokens.len()];
+            api_object.augment_labeled(vec![(tokens, labels)], String::from("O"), None)
+        };
+        let result = labeled(&["New-York", "."]).unwrap();
+        assert_eq!(result[0].0.len(), 2);
+        assert!(labeled(&["New York", "."]).is_err());
+        assert!(labeled(&["", "."]).is_err());
+        assert!(labeled(&["\t"]).is_err());
+    }
+
+    #[test]
+    fn test_same_output_for_any_threads() {
+        let configs = [
+            r#"{"type": "random_char", "action": "substitute", "aug_p_char": 0.5, "aug_p_word": 0.5,
+                "include_upper_case": true, "include_lower_case": true, "include_numeric": true,
+                "include_special_char": false, "lang": "en", "swap_mode": "adjacent", "seed": 5}"#,
+            r#"{"type": "random_word", "action": "swap", "aug_p_word": 0.5, "seed": 5}"#,
+            r#"{"type": "pipeline", "mode": "one_of", "weights": [3.0, 1.0], "seed": 5, "steps": [
+                {"type": "split", "aug_min_word": 1, "aug_p_word": 0.3, "min_char": 4},
+                {"type": "random_word", "action": "swap", "aug_p_word": 0.3}]}"#,
+        ];
+        let inputs: Vec<String> = (0..7)
+            .map(|idx| format!("The quick brown fox {} jumps over the lazy dog", idx))
+            .collect();
+        for config in configs {
+            let outputs: Vec<Vec<String>> = [1, 2, 3]
+                .iter()
+                .map(|n_threads| {
+                    let api = AugmenterConfig::parse(config, ConfigFormat::Json)
+                        .unwrap()
+                        .build_api()
+                        .unwrap();
+                    let mut output = api.augment_list(inputs.clone(), *n_threads, None);
+                    output.extend(api.augment_list(inputs.clone(), *n_threads, Some(11)));
+                    output
+                })
+                .collect();
+            assert_eq!(outputs[0].len(), inputs.len() * 2);
+            assert_ne!(outputs[0][..inputs.len()], inputs[..]);
+            assert_eq!(outputs[0], outputs[1]);
+            assert_eq!(outputs[0], outputs[2]);
+        }
+
+        let config: RandomWordConfig =
+            serde_json::from_str(r#"{"action": "swap", "aug_p_word": 0.5, "seed": 5}"#).unwrap();
+        let api_object = RustRandomWordApiClass::from_config(config).unwrap();
+        let input = String::from("The quick brown fox jumps over the lazy dog");
+        let single = api_object.augment_string_single_thread(input.clone(), 7, Some(11));
+        for n_threads in [2, 3] {
+            assert_eq!(
+                api_object.augment_string_multi_thread(input.clone(), 7, n_threads, Some(11)),
+                single
+            );
+        }
+    }
+}
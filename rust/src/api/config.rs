@@ -0,0 +1,334 @@
+#[cfg(feature = "python")]
+use super::value_error;
+use super::{
+    AntonymConfig, ConfusionConfig, HomoglyphConfig, KeyboardConfig, LayoutSwitchConfig, OcrConfig,
+    RandomCharConfig, RandomWordConfig, ReservedConfig, SpellingConfig, SplitConfig, SynonymConfig,
+    TfIdfConfig, WordEmbsConfig,
+};
+use super::{
+    ApiResult, DynApiClass, PipelineConfig, RustAntonymApiClass, RustBaseApiClass,
+    RustConfusionApiClass, RustHomoglyphApiClass, RustKeyboardApiClass, RustLayoutSwitchApiClass,
+    RustOCRApiClass, RustPipelineApiClass, RustRandomCharApiClass, RustRandomWordApiClass,
+    RustReservedApiClass, RustSpellingApiClass, RustSplitApiClass, RustSynonymApiClass,
+    RustTfIdfApiClass, RustWordEmbsApiClass,
+};
+use crate::aug::DocAugmentor;
+#[cfg(feature = "python")]
+use pyo3::exceptions::PyTypeError;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize, Serializer};
+use std::sync::Arc;
+
+pub(crate) fn default_stopwords_mode() -> String {
+    String::from("exact")
+}
+
+pub(crate) fn default_tokenizer() -> String {
+    String::from("alphanumeric")
+}
+
+/// Shortest decimal, that is parsed back to the same f32: 0.3 instead of 0.30000001192092896
+fn shortest_f64(value: f32) -> f64 {
+    value.to_string().parse().unwrap()
+}
+
+pub(crate) fn serialize_f32<S: Serializer>(value: &f32, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(shortest_f64(*value))
+}
+
+pub(crate) fn serialize_option_f32<S: Serializer>(
+    value: &Option<f32>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.serialize_some(&shortest_f64(*value)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Format of serialized config: 'json' or 'toml'
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigFormat {
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    pub fn from_name(name: &str) -> ApiResult<Self> {
+        match name {
+            "json" => Ok(ConfigFormat::Json),
+            "toml" => Ok(ConfigFormat::Toml),
+            _ => Err(format!("unknown config format '{}'", name).into()),
+        }
+    }
+}
+
+macro_rules! augmenter_configs {
+    ($($name:literal => $variant:ident($config:ty, $api_class:ty)),* $(,)?) => {
+        /// Config of any augmenter, its `type` is the name of augmenter
+        ///
+        /// Fields of config are the parameters of Rust*ApiClass constructor
+        #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+        #[serde(tag = "type")]
+        pub enum AugmenterConfig {
+            $(
+                #[serde(rename = $name)]
+                $variant($config),
+            )*
+        }
+
+        impl AugmenterConfig {
+            /// Create Rust*ApiClass, described by config
+            #[cfg(feature = "python")]
+            pub fn build(self, py: Python) -> PyResult<PyObject> {
+                match self {
+                    $(AugmenterConfig::$variant(config) => {
+                        let api_object = <$api_class>::from_config(config).map_err(value_error)?;
+                        Ok(Py::new(py, api_object)?.into_py(py))
+                    })*
+                }
+            }
+
+            /// Create augmentor, described by config, to use it as a step of pipeline
+            pub fn build_step(self) -> ApiResult<Arc<dyn DocAugmentor>> {
+                match self {
+                    $(AugmenterConfig::$variant(config) => {
+                        let api_object = <$api_class>::from_config(config)?;
+                        Ok(Arc::new(api_object.create_augmentor_instance()))
+                    })*
+                }
+            }
+
+            /// Create Rust*ApiClass, described by config, without Python object
+            pub fn build_api(self) -> ApiResult<Box<dyn DynApiClass>> {
+                match self {
+                    $(AugmenterConfig::$variant(config) => {
+                        Ok(Box::new(<$api_class>::from_config(config)?))
+                    })*
+                }
+            }
+
+            /// Tokenizer and protected spans of augmenter: (tokenizer, token_pattern, protected_detectors, protected_patterns)
+            pub fn text_settings(
+                &self,
+            ) -> (&String, &Option<String>, &Option<Vec<String>>, &Option<Vec<String>>) {
+                match self {
+                    $(AugmenterConfig::$variant(config) => (
+                        &config.tokenizer,
+                        &config.token_pattern,
+                        &config.protected_detectors,
+                        &config.protected_patterns,
+                    ),)*
+                }
+            }
+
+            /// Override seed of augmenter
+            pub fn set_seed(&mut self, seed: u64) {
+                match self {
+                    $(AugmenterConfig::$variant(config) => config.seed = Some(seed),)*
+                }
+            }
+
+            /// Take augmentor and config of existing Rust*ApiClass
+            ///
+            /// Raises TypeError, if object is not an augmenter
+            #[cfg(feature = "python")]
+            pub fn from_api_object(
+                api_object: &PyAny,
+            ) -> PyResult<(Arc<dyn DocAugmentor>, AugmenterConfig)> {
+                $(
+                    if let Ok(api_object) = api_object.extract::<PyRef<$api_class>>() {
+                        return Ok((
+                            Arc::new(api_object.create_augmentor_instance()),
+                            AugmenterConfig::$variant(api_object.get_config()),
+                        ));
+                    }
+                )*
+                Err(PyTypeError::new_err(format!(
+                    "expected an augmenter, got {}",
+                    api_object.get_type().name()?
+                )))
+            }
+        }
+
+        $(
+            impl DynApiClass for $api_class {
+                fn augment_list(
+                    &self,
+                    input_list: Vec<String>,
+                    n_threads: usize,
+                    seed: Option<u64>,
+                ) -> Vec<String> {
+                    if n_threads > 1 {
+                        self.augment_list_multi_thread(input_list, n_threads, seed)
+                    } else {
+                        self.augment_list_single_thread(input_list, seed)
+                    }
+                }
+
+                fn next_seed(&self) -> u64 {
+                    self.get_seed_source().next_seed(None)
+                }
+            }
+        )*
+    };
+}
+
+augmenter_configs!(
+    "ocr" => Ocr(OcrConfig, RustOCRApiClass),
+    "keyboard" => Keyboard(KeyboardConfig, RustKeyboardApiClass),
+    "layout_switch" => LayoutSwitch(LayoutSwitchConfig, RustLayoutSwitchApiClass),
+    "random_char" => RandomChar(RandomCharConfig, RustRandomCharApiClass),
+    "homoglyph" => Homoglyph(HomoglyphConfig, RustHomoglyphApiClass),
+    "confusion" => Confusion(ConfusionConfig, RustConfusionApiClass),
+    "random_word" => RandomWord(RandomWordConfig, RustRandomWordApiClass),
+    "spelling" => Spelling(SpellingConfig, RustSpellingApiClass),
+    "synonym" => Synonym(SynonymConfig, RustSynonymApiClass),
+    "antonym" => Antonym(AntonymConfig, RustAntonymApiClass),
+    "split" => Split(SplitConfig, RustSplitApiClass),
+    "reserved" => Reserved(ReservedConfig, RustReservedApiClass),
+    "tfidf" => TfIdf(TfIdfConfig, RustTfIdfApiClass),
+    "word_embs" => WordEmbs(WordEmbsConfig, RustWordEmbsApiClass),
+    "pipeline" => Pipeline(PipelineConfig, RustPipelineApiClass),
+);
+
+impl AugmenterConfig {
+    /// Parse config from JSON or TOML string
+    ///
+    /// Returns error on invalid config
+    pub fn parse(config: &str, format: ConfigFormat) -> ApiResult<Self> {
+        match format {
+            ConfigFormat::Json => Ok(serde_json::from_str(config)?),
+            ConfigFormat::Toml => Ok(toml::from_str(config)?),
+        }
+    }
+
+    /// Serialize config to JSON or TOML string
+    pub fn dump(&self, format: ConfigFormat) -> ApiResult<String> {
+        match format {
+            ConfigFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            ConfigFormat::Toml => Ok(toml::to_string(self)?),
+        }
+    }
+}
+
+/// Create Rust*ApiClass from config in `format`: 'json' or 'toml'
+#[cfg(feature = "python")]
+#[pyfunction]
+#[pyo3(signature = (config, format="json"))]
+pub fn load_config(py: Python, config: &str, format: &str) -> PyResult<PyObject> {
+    ConfigFormat::from_name(format)
+        .and_then(|format| AugmenterConfig::parse(config, format))
+        .map_err(value_error)?
+        .build(py)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PIPELINE_TOML: &str = r#"
+type = "pipeline"
+mode = "one_of"
+weights = [3.0, 1.0]
+seed = 42
+
+[[steps]]
+type = "split"
+aug_min_word = 1
+aug_p_word = 0.3
+min_char = 4
+stopwords = ["the", "a"]
+
+[[steps]]
+type = "random_word"
+action = "swap"
+aug_p_word = 0.3
+"#;
+
+    #[test]
+    fn test_parse_with_defaults() {
+        let config = AugmenterConfig::parse(PIPELINE_TOML, ConfigFormat::Toml).unwrap();
+        let pipeline = match config {
+            AugmenterConfig::Pipeline(pipeline) => pipeline,
+            _ => panic!("expected pipeline config"),
+        };
+        assert_eq!(pipeline.steps.len(), 2);
+        match &pipeline.steps[0] {
+            AugmenterConfig::Split(split) => {
+                assert_eq!(split.aug_max_word, None);
+                assert_eq!(split.stopwords_mode, "exact");
+                assert_eq!(split.tokenizer, "alphanumeric");
+            }
+            _ => panic!("expected split config"),
+        }
+    }
+
+    #[test]
+    fn test_json_toml_round_trip() {
+        let config = AugmenterConfig::parse(PIPELINE_TOML, ConfigFormat::Toml).unwrap();
+        for format in [ConfigFormat::Json, ConfigFormat::Toml] {
+            let dumped = config.dump(format).unwrap();
+            assert!(!dumped.contains("0.30000001192092896"));
+            assert_eq!(AugmenterConfig::parse(&dumped, format).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn test_build_reproduces_config() {
+        let config = AugmenterConfig::parse(PIPELINE_TOML, ConfigFormat::Toml).unwrap();
+        let api_object = match config.clone() {
+            AugmenterConfig::Pipeline(pipeline) => {
+                RustPipelineApiClass::from_config(pipeline).unwrap()
+            }
+            _ => panic!("expected pipeline config"),
+        };
+        assert_eq!(AugmenterConfig::Pipeline(api_object.get_config()), config);
+    }
+
+    #[test]
+    fn test_step_with_other_text_settings() {
+        for setting in [
+            "tokenizer = \"whitespace\"",
+            "protected_detectors = [\"url\"]",
+        ] {
+            let config = format!("{}{}\n", PIPELINE_TOML, setting);
+            let config = AugmenterConfig::parse(&config, ConfigFormat::Toml).unwrap();
+            assert!(config.build_api().is_err());
+        }
+        let config =
+            PIPELINE_TOML.replacen("seed = 42", "seed = 42\nprotected_detectors = [\"url\"]", 1);
+        let config = AugmenterConfig::parse(&config, ConfigFormat::Toml).unwrap();
+        assert!(config.build_api().is_err());
+    }
+
+    #[test]
+    fn test_invalid_configs() {
+        let unknown_type = "{\"type\": \"unknown\"}";
+        assert!(AugmenterConfig::parse(unknown_type, ConfigFormat::Json).is_err());
+        let unknown_field = PIPELINE_TOML.replace("min_char", "min_chars");
+        assert!(AugmenterConfig::parse(&unknown_field, ConfigFormat::Toml).is_err());
+        let missing_field = "{\"type\": \"random_word\"}";
+        assert!(AugmenterConfig::parse(missing_field, ConfigFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_invalid_keyboard_layout() {
+        for (layout, is_ok) in [
+            ("qwerty", true),
+            ("test_res/keyboard_layout.json", true),
+            ("test_res/no_layout.json", false),
+            ("test_res/good_mapping.json", false),
+        ] {
+            let config = format!(
+                "{{\"type\": \"keyboard\", \"action\": \"substitute\", \"layout\": \"{}\",
+                \"include_special_char\": false, \"include_numeric\": false,
+                \"include_upper_case\": true, \"model_path\": \"\", \"max_distance\": 1.5}}",
+                layout
+            );
+            let config = AugmenterConfig::parse(&config, ConfigFormat::Json).unwrap();
+            assert_eq!(config.build_api().is_ok(), is_ok);
+        }
+    }
+}
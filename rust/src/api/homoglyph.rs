@@ -0,0 +1,358 @@
+use super::{
+    create_doc, create_protected_spans, create_stopwords, create_tokenizer, ApiResult,
+    RustBaseApiClass, SeedSource,
+};
+#[cfg(feature = "python")]
+use super::{value_error, AugmenterConfig, ConfigFormat, LabeledTokens};
+use crate::aug::character::HomoglyphAugmentor;
+use crate::aug::{AugCountParams, BaseAugmentor, Stopwords};
+#[cfg(feature = "python")]
+use crate::doc::SpanAlignment;
+use crate::doc::{ProtectedSpans, Tokenizer};
+use crate::model::character::HomoglyphModel;
+use crate::utils;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+#[cfg(feature = "python")]
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+/// Config of RustHomoglyphApiClass: parameters of its constructor
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct HomoglyphConfig {
+    pub aug_min_char: Option<usize>,
+    pub aug_max_char: Option<usize>,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub aug_p_char: Option<f32>,
+    pub aug_min_word: Option<usize>,
+    pub aug_max_word: Option<usize>,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub aug_p_word: Option<f32>,
+    pub stopwords: Option<BTreeSet<String>>,
+    #[serde(default = "super::config::default_stopwords_mode")]
+    pub stopwords_mode: String,
+    pub min_char: Option<usize>,
+    pub dict_of_path: String,
+    #[serde(default)]
+    pub use_graphemes: bool,
+    #[serde(default = "super::config::default_tokenizer")]
+    pub tokenizer: String,
+    pub token_pattern: Option<String>,
+    pub protected_detectors: Option<Vec<String>>,
+    pub protected_patterns: Option<Vec<String>>,
+    pub seed: Option<u64>,
+}
+
+/// Api Class to perform Homoglyph model augmentations on input
+#[cfg_attr(feature = "python", pyclass)]
+pub struct RustHomoglyphApiClass {
+    /// Parameteres to calculate number of chars that will be augmented in single word
+    aug_char_params: AugCountParams,
+    /// Parameteres to calculate number of words that will be augmented
+    aug_word_params: AugCountParams,
+    /// HomoglyphModel
+    model: Arc<HomoglyphModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_char: Option<usize>,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
+    /// Splits input to tokens: 'alphanumeric', 'whitespace', 'regex' or 'unicode'
+    tokenizer: Arc<dyn Tokenizer>,
+    /// Spans of input, that are never augmented: URLs, emails, numbers, regex matches, ...
+    protected_spans: Arc<Option<ProtectedSpans>>,
+    /// Source of base seeds for augmentations
+    seed_source: SeedSource,
+    /// Parameters, the augmenter was created with
+    config: HomoglyphConfig,
+}
+
+impl RustHomoglyphApiClass {
+    /// Create augmenter from its config
+    pub fn from_config(config: HomoglyphConfig) -> ApiResult<Self> {
+        let HomoglyphConfig {
+            aug_min_char,
+            aug_max_char,
+            aug_p_char,
+            aug_min_word,
+            aug_max_word,
+            aug_p_word,
+            stopwords,
+            stopwords_mode,
+            min_char,
+            dict_of_path,
+            use_graphemes,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        } = config.clone();
+        let stopwords = stopwords.map(|words| words.into_iter().collect());
+        let mut model = HomoglyphModel::new(dict_of_path);
+        model.load_model()?;
+        Ok(RustHomoglyphApiClass {
+            aug_char_params: AugCountParams::new(aug_min_char, aug_max_char, aug_p_char),
+            aug_word_params: AugCountParams::new(aug_min_word, aug_max_word, aug_p_word),
+            model: Arc::new(model),
+            stopwords: Arc::new(create_stopwords(stopwords, &stopwords_mode)?),
+            min_char,
+            use_graphemes,
+            tokenizer: create_tokenizer(&tokenizer, token_pattern)?,
+            protected_spans: Arc::new(create_protected_spans(
+                protected_detectors,
+                protected_patterns,
+            )?),
+            seed_source: SeedSource::new(seed),
+            config,
+        })
+    }
+
+    /// Config of augmenter with the seed, it was created with
+    pub fn get_config(&self) -> HomoglyphConfig {
+        HomoglyphConfig {
+            seed: Some(self.seed_source.seed()),
+            ..self.config.clone()
+        }
+    }
+
+    fn get_aug_char_params(&self) -> AugCountParams {
+        self.aug_char_params.clone()
+    }
+
+    fn get_aug_word_params(&self) -> AugCountParams {
+        self.aug_word_params.clone()
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_char
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl RustHomoglyphApiClass {
+    #[new]
+    #[pyo3(signature = (
+        aug_min_char, aug_max_char, aug_p_char,
+        aug_min_word, aug_max_word, aug_p_word,
+        stopwords, stopwords_mode, min_char, dict_of_path,
+        use_graphemes, tokenizer, token_pattern,
+        protected_detectors, protected_patterns, seed)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        aug_min_char: Option<usize>,
+        aug_max_char: Option<usize>,
+        aug_p_char: Option<f32>,
+        aug_min_word: Option<usize>,
+        aug_max_word: Option<usize>,
+        aug_p_word: Option<f32>,
+        stopwords: Option<HashSet<String>>,
+        stopwords_mode: String,
+        min_char: Option<usize>,
+        dict_of_path: String,
+        use_graphemes: bool,
+        tokenizer: String,
+        token_pattern: Option<String>,
+        protected_detectors: Option<Vec<String>>,
+        protected_patterns: Option<Vec<String>>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        Self::from_config(HomoglyphConfig {
+            aug_min_char,
+            aug_max_char,
+            aug_p_char,
+            aug_min_word,
+            aug_max_word,
+            aug_p_word,
+            stopwords: stopwords.map(|words| words.into_iter().collect()),
+            stopwords_mode,
+            min_char,
+            dict_of_path,
+            use_graphemes,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        })
+        .map_err(value_error)
+    }
+
+    #[pyo3(name = "get_config", signature = (format="json"))]
+    fn dump_config(&self, format: &str) -> PyResult<String> {
+        ConfigFormat::from_name(format)
+            .and_then(|format| AugmenterConfig::Homoglyph(self.get_config()).dump(format))
+            .map_err(value_error)
+    }
+
+    #[pyo3(signature = (input_string, n, seed=None))]
+    fn augment_string_single_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_single_thread(self, input_string, n, seed)
+    }
+
+    #[pyo3(signature = (input_string, n, n_threads, seed=None))]
+    fn augment_string_multi_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_multi_thread(self, input_string, n, n_threads, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_list_single_thread(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_single_thread(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (input_list, n_threads, seed=None))]
+    fn augment_list_multi_thread(
+        &self,
+        input_list: Vec<String>,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_multi_thread(self, input_list, n_threads, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_with_alignment(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, Vec<SpanAlignment>)> {
+        RustBaseApiClass::augment_with_alignment(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (inputs, default_label, seed=None))]
+    fn augment_labeled(
+        &self,
+        inputs: Vec<LabeledTokens>,
+        default_label: String,
+        seed: Option<u64>,
+    ) -> PyResult<Vec<LabeledTokens>> {
+        RustBaseApiClass::augment_labeled(self, inputs, default_label, seed).map_err(value_error)
+    }
+}
+
+impl RustBaseApiClass<HomoglyphAugmentor, HomoglyphModel> for RustHomoglyphApiClass {
+    fn create_augmentor_instance(&self) -> HomoglyphAugmentor {
+        HomoglyphAugmentor::new(
+            self.get_aug_char_params(),
+            self.get_aug_word_params(),
+            self.get_min_chars(),
+            Arc::clone(&self.model),
+            Arc::clone(&self.stopwords),
+            self.use_graphemes,
+        )
+    }
+
+    fn get_tokenizer(&self) -> Arc<dyn Tokenizer> {
+        Arc::clone(&self.tokenizer)
+    }
+
+    fn get_protected_spans(&self) -> Arc<Option<ProtectedSpans>> {
+        Arc::clone(&self.protected_spans)
+    }
+
+    fn get_seed_source(&self) -> &SeedSource {
+        &self.seed_source
+    }
+
+    fn create_thread_handle_string(
+        &self,
+        input_string_ref: Arc<String>,
+        n_on_thread: usize,
+        first_idx: usize,
+        seed: u64,
+    ) -> thread::JoinHandle<Vec<String>> {
+        let aug_params_char_cloned = self.get_aug_char_params();
+        let aug_params_word_cloned = self.get_aug_word_params();
+        let min_chars_cloned = self.get_min_chars();
+        let arc_model_ref = Arc::clone(&self.model);
+        let arc_stopword_ref = Arc::clone(&self.stopwords);
+        let use_graphemes_cloned = self.use_graphemes;
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
+
+        let thread_handle = thread::spawn(move || {
+            let mut thread_res = Vec::with_capacity(n_on_thread);
+            let mut doc = create_doc(
+                &input_string_ref,
+                arc_tokenizer_ref.as_ref(),
+                &arc_protected_ref,
+            );
+            let augmentor = HomoglyphAugmentor::new(
+                aug_params_char_cloned,
+                aug_params_word_cloned,
+                min_chars_cloned,
+                arc_model_ref,
+                arc_stopword_ref,
+                use_graphemes_cloned,
+            );
+            for idx in first_idx..first_idx + n_on_thread {
+                let mut rng = utils::item_rng(seed, idx);
+                augmentor.augment(&mut doc, &mut rng);
+                thread_res.push(doc.get_augmented_string());
+                doc.set_to_original();
+            }
+            thread_res
+        });
+        thread_handle
+    }
+
+    fn create_thread_handle_list(
+        &self,
+        input_list_ref: Arc<Vec<String>>,
+        left_idx: usize,
+        right_idx: usize,
+        seed: u64,
+    ) -> thread::JoinHandle<Vec<String>> {
+        let aug_params_char_cloned = self.get_aug_char_params();
+        let aug_params_word_cloned = self.get_aug_word_params();
+        let min_chars_cloned = self.get_min_chars();
+        let arc_model_ref = Arc::clone(&self.model);
+        let arc_stopword_ref = Arc::clone(&self.stopwords);
+        let use_graphemes_cloned = self.use_graphemes;
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
+
+        let thread_handle = thread::spawn(move || {
+            let mut thread_res = Vec::with_capacity(right_idx - left_idx);
+            let augmentor = HomoglyphAugmentor::new(
+                aug_params_char_cloned,
+                aug_params_word_cloned,
+                min_chars_cloned,
+                arc_model_ref,
+                arc_stopword_ref,
+                use_graphemes_cloned,
+            );
+
+            for (idx, input) in input_list_ref[left_idx..right_idx].iter().enumerate() {
+                let mut rng = utils::item_rng(seed, left_idx + idx);
+                let mut doc = create_doc(input, arc_tokenizer_ref.as_ref(), &arc_protected_ref);
+                augmentor.augment(&mut doc, &mut rng);
+                thread_res.push(doc.get_augmented_string());
+            }
+            thread_res
+        });
+        thread_handle
+    }
+}
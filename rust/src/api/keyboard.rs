@@ -1,17 +1,66 @@
-use super::RustBaseApiClass;
+use super::{
+    create_action, create_doc, create_keyboard_layout, create_protected_spans, create_stopwords,
+    create_tokenizer, ApiResult, RustBaseApiClass, SeedSource,
+};
+#[cfg(feature = "python")]
+use super::{value_error, AugmenterConfig, ConfigFormat, LabeledTokens};
 use crate::aug::character::KeyboardAugmentor;
-use crate::aug::{AugCountParams, BaseAugmentor};
-use crate::doc::Doc;
+use crate::aug::{Action, AugCountParams, BaseAugmentor, Stopwords};
+#[cfg(feature = "python")]
+use crate::doc::SpanAlignment;
+use crate::doc::{ProtectedSpans, Tokenizer};
 use crate::model::character::KeyboardModel;
+use crate::utils;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+#[cfg(feature = "python")]
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::thread;
 
+/// Config of RustKeyboardApiClass: parameters of its constructor
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct KeyboardConfig {
+    pub action: String,
+    pub aug_min_char: Option<usize>,
+    pub aug_max_char: Option<usize>,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub aug_p_char: Option<f32>,
+    pub aug_min_word: Option<usize>,
+    pub aug_max_word: Option<usize>,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub aug_p_word: Option<f32>,
+    pub stopwords: Option<BTreeSet<String>>,
+    #[serde(default = "super::config::default_stopwords_mode")]
+    pub stopwords_mode: String,
+    pub include_special_char: bool,
+    pub include_numeric: bool,
+    pub include_upper_case: bool,
+    pub min_char: Option<usize>,
+    pub model_path: String,
+    pub layout: Option<String>,
+    #[serde(serialize_with = "super::config::serialize_f32")]
+    pub max_distance: f32,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub distance_decay: Option<f32>,
+    #[serde(default)]
+    pub use_graphemes: bool,
+    #[serde(default = "super::config::default_tokenizer")]
+    pub tokenizer: String,
+    pub token_pattern: Option<String>,
+    pub protected_detectors: Option<Vec<String>>,
+    pub protected_patterns: Option<Vec<String>>,
+    pub seed: Option<u64>,
+}
+
 /// Api Class to perform Keyboard model augmentations on input
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct RustKeyboardApiClass {
+    /// Action to augmentation, set of values {'substitute', 'insert', 'delete'}
+    action: Action,
     /// Parameteres to calculate number of chars that will be augmented in single word
     aug_char_params: AugCountParams,
     /// Parameteres to calculate number of words that will be augmented in string
@@ -19,12 +68,101 @@ pub struct RustKeyboardApiClass {
     /// KeyboardModel
     model: Arc<KeyboardModel>,
     /// Filter, Set of words that cannot be augmented
-    stopwords: Arc<Option<HashSet<String>>>,
+    stopwords: Arc<Option<Stopwords>>,
     /// Filter, do not augment word, if it's lenght less than this value
     min_char: Option<usize>,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
+    /// Splits input to tokens: 'alphanumeric', 'whitespace', 'regex' or 'unicode'
+    tokenizer: Arc<dyn Tokenizer>,
+    /// Spans of input, that are never augmented: URLs, emails, numbers, regex matches, ...
+    protected_spans: Arc<Option<ProtectedSpans>>,
+    /// Source of base seeds for augmentations
+    seed_source: SeedSource,
+    /// Parameters, the augmenter was created with
+    config: KeyboardConfig,
 }
 
 impl RustKeyboardApiClass {
+    /// Create augmenter from its config
+    pub fn from_config(config: KeyboardConfig) -> ApiResult<Self> {
+        let KeyboardConfig {
+            action,
+            aug_min_char,
+            aug_max_char,
+            aug_p_char,
+            aug_min_word,
+            aug_max_word,
+            aug_p_word,
+            stopwords,
+            stopwords_mode,
+            include_special_char,
+            include_numeric,
+            include_upper_case,
+            min_char,
+            model_path,
+            layout,
+            max_distance,
+            distance_decay,
+            use_graphemes,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        } = config.clone();
+        let action = create_action(&action, &KeyboardAugmentor::ACTIONS)?;
+        let stopwords = stopwords.map(|words| words.into_iter().collect());
+        let model = match layout {
+            // Built-in layout name or path to json-file with custom layout
+            Some(layout) => {
+                let layout = create_keyboard_layout(&layout)?;
+                KeyboardModel::from_layout(
+                    include_special_char,
+                    include_numeric,
+                    include_upper_case,
+                    &layout,
+                    max_distance,
+                    distance_decay,
+                )
+            }
+            None => {
+                let mut model = KeyboardModel::new(
+                    include_special_char,
+                    include_numeric,
+                    include_upper_case,
+                    model_path,
+                );
+                model.load_model()?;
+                model
+            }
+        };
+        Ok(RustKeyboardApiClass {
+            action,
+            aug_char_params: AugCountParams::new(aug_min_char, aug_max_char, aug_p_char),
+            aug_word_params: AugCountParams::new(aug_min_word, aug_max_word, aug_p_word),
+            model: Arc::new(model),
+            stopwords: Arc::new(create_stopwords(stopwords, &stopwords_mode)?),
+            min_char: min_char,
+            use_graphemes,
+            tokenizer: create_tokenizer(&tokenizer, token_pattern)?,
+            protected_spans: Arc::new(create_protected_spans(
+                protected_detectors,
+                protected_patterns,
+            )?),
+            seed_source: SeedSource::new(seed),
+            config,
+        })
+    }
+
+    /// Config of augmenter with the seed, it was created with
+    pub fn get_config(&self) -> KeyboardConfig {
+        KeyboardConfig {
+            seed: Some(self.seed_source.seed()),
+            ..self.config.clone()
+        }
+    }
+
     fn get_aug_char_params(&self) -> AugCountParams {
         self.aug_char_params.clone()
     }
@@ -38,16 +176,22 @@ impl RustKeyboardApiClass {
     }
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl RustKeyboardApiClass {
     #[new]
     #[pyo3(signature = (
-        aug_min_char, aug_max_char, aug_p_char,
+        action, aug_min_char, aug_max_char, aug_p_char,
         aug_min_word, aug_max_word, aug_p_word,
-        stopwords, include_special_char, include_numeric,
-        include_upper_case, min_char, model_path)
+        stopwords, stopwords_mode, include_special_char, include_numeric,
+        include_upper_case, min_char, model_path,
+        layout, max_distance, distance_decay,
+        use_graphemes, tokenizer, token_pattern,
+        protected_detectors, protected_patterns, seed)
     )]
+    #[allow(clippy::too_many_arguments)]
     fn new(
+        action: String,
         aug_min_char: Option<usize>,
         aug_max_char: Option<usize>,
         aug_p_char: Option<f32>,
@@ -55,84 +199,177 @@ impl RustKeyboardApiClass {
         aug_max_word: Option<usize>,
         aug_p_word: Option<f32>,
         stopwords: Option<HashSet<String>>,
+        stopwords_mode: String,
         include_special_char: bool,
         include_numeric: bool,
         include_upper_case: bool,
         min_char: Option<usize>,
         model_path: String,
-    ) -> Self {
-        let mut model = KeyboardModel::new(
+        layout: Option<String>,
+        max_distance: f32,
+        distance_decay: Option<f32>,
+        use_graphemes: bool,
+        tokenizer: String,
+        token_pattern: Option<String>,
+        protected_detectors: Option<Vec<String>>,
+        protected_patterns: Option<Vec<String>>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        Self::from_config(KeyboardConfig {
+            action,
+            aug_min_char,
+            aug_max_char,
+            aug_p_char,
+            aug_min_word,
+            aug_max_word,
+            aug_p_word,
+            stopwords: stopwords.map(|words| words.into_iter().collect()),
+            stopwords_mode,
             include_special_char,
             include_numeric,
             include_upper_case,
+            min_char,
             model_path,
-        );
-        model.load_model();
-        RustKeyboardApiClass {
-            aug_char_params: AugCountParams::new(aug_min_char, aug_max_char, aug_p_char),
-            aug_word_params: AugCountParams::new(aug_min_word, aug_max_word, aug_p_word),
-            model: Arc::new(model),
-            stopwords: Arc::new(stopwords),
-            min_char: min_char,
-        }
+            layout,
+            max_distance,
+            distance_decay,
+            use_graphemes,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        })
+        .map_err(value_error)
+    }
+
+    #[pyo3(name = "get_config", signature = (format="json"))]
+    fn dump_config(&self, format: &str) -> PyResult<String> {
+        ConfigFormat::from_name(format)
+            .and_then(|format| AugmenterConfig::Keyboard(self.get_config()).dump(format))
+            .map_err(value_error)
     }
 
-    fn augment_string_single_thread(&self, input_string: String, n: usize) -> Vec<String> {
-        RustBaseApiClass::augment_string_single_thread(self, input_string, n)
+    #[pyo3(signature = (input_string, n, seed=None))]
+    fn augment_string_single_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_single_thread(self, input_string, n, seed)
     }
 
+    #[pyo3(signature = (input_string, n, n_threads, seed=None))]
     fn augment_string_multi_thread(
         &self,
         input_string: String,
         n: usize,
         n_threads: usize,
+        seed: Option<u64>,
     ) -> Vec<String> {
-        RustBaseApiClass::augment_string_multi_thread(self, input_string, n, n_threads)
+        RustBaseApiClass::augment_string_multi_thread(self, input_string, n, n_threads, seed)
     }
 
-    fn augment_list_single_thread(&self, input_list: Vec<String>) -> Vec<String> {
-        RustBaseApiClass::augment_list_single_thread(self, input_list)
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_list_single_thread(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_single_thread(self, input_list, seed)
     }
 
-    fn augment_list_multi_thread(&self, input_list: Vec<String>, n_threads: usize) -> Vec<String> {
-        RustBaseApiClass::augment_list_multi_thread(self, input_list, n_threads)
+    #[pyo3(signature = (input_list, n_threads, seed=None))]
+    fn augment_list_multi_thread(
+        &self,
+        input_list: Vec<String>,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_multi_thread(self, input_list, n_threads, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_with_alignment(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, Vec<SpanAlignment>)> {
+        RustBaseApiClass::augment_with_alignment(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (inputs, default_label, seed=None))]
+    fn augment_labeled(
+        &self,
+        inputs: Vec<LabeledTokens>,
+        default_label: String,
+        seed: Option<u64>,
+    ) -> PyResult<Vec<LabeledTokens>> {
+        RustBaseApiClass::augment_labeled(self, inputs, default_label, seed).map_err(value_error)
     }
 }
 
 impl RustBaseApiClass<KeyboardAugmentor, KeyboardModel> for RustKeyboardApiClass {
     fn create_augmentor_instance(&self) -> KeyboardAugmentor {
         KeyboardAugmentor::new(
+            self.action,
             self.get_aug_char_params(),
             self.get_aug_word_params(),
             self.get_min_chars(),
             Arc::clone(&self.model),
             Arc::clone(&self.stopwords),
+            self.use_graphemes,
         )
     }
 
+    fn get_tokenizer(&self) -> Arc<dyn Tokenizer> {
+        Arc::clone(&self.tokenizer)
+    }
+
+    fn get_protected_spans(&self) -> Arc<Option<ProtectedSpans>> {
+        Arc::clone(&self.protected_spans)
+    }
+
+    fn get_seed_source(&self) -> &SeedSource {
+        &self.seed_source
+    }
+
     fn create_thread_handle_string(
         &self,
         input_string_ref: Arc<String>,
         n_on_thread: usize,
+        first_idx: usize,
+        seed: u64,
     ) -> thread::JoinHandle<Vec<String>> {
+        let action_cloned = self.action;
         let aug_params_char_cloned = self.get_aug_char_params();
         let aug_params_word_cloned = self.get_aug_word_params();
         let min_chars_cloned = self.get_min_chars();
         let arc_model_ref = Arc::clone(&self.model);
         let arc_stopword_ref = Arc::clone(&self.stopwords);
+        let use_graphemes_cloned = self.use_graphemes;
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
 
         let thread_handle = thread::spawn(move || {
-            let mut rng: StdRng = SeedableRng::from_entropy();
             let mut thread_res = Vec::with_capacity(n_on_thread);
-            let mut doc = Doc::from_arc(input_string_ref);
+            let mut doc = create_doc(
+                &input_string_ref,
+                arc_tokenizer_ref.as_ref(),
+                &arc_protected_ref,
+            );
             let augmentor = KeyboardAugmentor::new(
+                action_cloned,
                 aug_params_char_cloned,
                 aug_params_word_cloned,
                 min_chars_cloned,
                 arc_model_ref,
                 arc_stopword_ref,
+                use_graphemes_cloned,
             );
-            for _ in 0..n_on_thread {
+            for idx in first_idx..first_idx + n_on_thread {
+                let mut rng = utils::item_rng(seed, idx);
                 augmentor.augment(&mut doc, &mut rng);
                 thread_res.push(doc.get_augmented_string());
                 doc.set_to_original();
@@ -147,26 +384,33 @@ impl RustBaseApiClass<KeyboardAugmentor, KeyboardModel> for RustKeyboardApiClass
         input_list_ref: Arc<Vec<String>>,
         left_idx: usize,
         right_idx: usize,
+        seed: u64,
     ) -> thread::JoinHandle<Vec<String>> {
+        let action_cloned = self.action;
         let aug_params_char_cloned = self.get_aug_char_params();
         let aug_params_word_cloned = self.get_aug_word_params();
         let min_chars_cloned = self.get_min_chars();
         let arc_model_ref = Arc::clone(&self.model);
         let arc_stopword_ref = Arc::clone(&self.stopwords);
+        let use_graphemes_cloned = self.use_graphemes;
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
 
         let thread_handle = thread::spawn(move || {
-            let mut rng: StdRng = SeedableRng::from_entropy();
             let mut thread_res = Vec::with_capacity(right_idx - left_idx);
             let augmentor = KeyboardAugmentor::new(
+                action_cloned,
                 aug_params_char_cloned,
                 aug_params_word_cloned,
                 min_chars_cloned,
                 arc_model_ref,
                 arc_stopword_ref,
+                use_graphemes_cloned,
             );
             // [left_idx..right_idx]
-            for input in &input_list_ref.as_ref()[left_idx..right_idx] {
-                let mut doc = Doc::new(input);
+            for (idx, input) in input_list_ref[left_idx..right_idx].iter().enumerate() {
+                let mut rng = utils::item_rng(seed, left_idx + idx);
+                let mut doc = create_doc(input, arc_tokenizer_ref.as_ref(), &arc_protected_ref);
                 augmentor.augment(&mut doc, &mut rng);
                 thread_res.push(doc.get_augmented_string());
             }
@@ -0,0 +1,299 @@
+use super::{
+    create_doc, create_protected_spans, create_tokenizer, ApiResult, AugmenterConfig,
+    RustBaseApiClass, SeedSource,
+};
+#[cfg(feature = "python")]
+use super::{value_error, ConfigFormat, LabeledTokens};
+use crate::aug::{BaseAugmentor, DocAugmentor, PipelineAugmentor, PipelineMode};
+#[cfg(feature = "python")]
+use crate::doc::SpanAlignment;
+use crate::doc::{ProtectedSpans, Tokenizer};
+use crate::model::EmptyModel;
+use crate::utils;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::thread;
+
+/// Config of RustPipelineApiClass: parameters of its constructor, steps are configs of augmenters
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfig {
+    pub steps: Vec<AugmenterConfig>,
+    pub mode: String,
+    pub weights: Option<Vec<f64>>,
+    #[serde(default = "super::config::default_tokenizer")]
+    pub tokenizer: String,
+    pub token_pattern: Option<String>,
+    pub protected_detectors: Option<Vec<String>>,
+    pub protected_patterns: Option<Vec<String>>,
+    pub seed: Option<u64>,
+}
+
+/// Api Class to apply several augmenters to input at once
+#[cfg_attr(feature = "python", pyclass)]
+pub struct RustPipelineApiClass {
+    /// Augmentor with all steps, they are created once from passed augmenters
+    augmentor: PipelineAugmentor,
+    /// Splits input to tokens: 'alphanumeric', 'whitespace', 'regex' or 'unicode'
+    tokenizer: Arc<dyn Tokenizer>,
+    /// Spans of input, that are never augmented: URLs, emails, numbers, regex matches, ...
+    protected_spans: Arc<Option<ProtectedSpans>>,
+    /// Source of base seeds for augmentations
+    seed_source: SeedSource,
+    /// Parameters, the augmenter was created with
+    config: PipelineConfig,
+}
+
+impl RustPipelineApiClass {
+    /// Create augmenter from its config, augmenters of steps are created from their configs too
+    pub fn from_config(config: PipelineConfig) -> ApiResult<Self> {
+        let steps = config
+            .steps
+            .iter()
+            .map(|step| step.clone().build_step())
+            .collect::<ApiResult<Vec<_>>>()?;
+        Self::with_steps(steps, config)
+    }
+
+    /// Config of augmenter with the seed, it was created with
+    pub fn get_config(&self) -> PipelineConfig {
+        PipelineConfig {
+            seed: Some(self.seed_source.seed()),
+            ..self.config.clone()
+        }
+    }
+
+    /// Returns error, if tokenizer or protected spans of any step differ from pipeline ones:
+    /// text is tokenized and protected only once, by pipeline
+    fn with_steps(steps: Vec<Arc<dyn DocAugmentor>>, config: PipelineConfig) -> ApiResult<Self> {
+        let text_settings = (
+            &config.tokenizer,
+            &config.token_pattern,
+            &config.protected_detectors,
+            &config.protected_patterns,
+        );
+        for (idx, step) in config.steps.iter().enumerate() {
+            if step.text_settings() != text_settings {
+                return Err(format!(
+                    "step {} must have the same tokenizer, token_pattern, protected_detectors \
+                    and protected_patterns as pipeline",
+                    idx
+                )
+                .into());
+            }
+        }
+        let PipelineConfig {
+            mode,
+            weights,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+            ..
+        } = config.clone();
+        let mode = PipelineMode::from_name(&mode)
+            .ok_or_else(|| format!("unknown pipeline mode '{}'", mode))?;
+        let weights = weights.unwrap_or_else(|| vec![1.0; steps.len()]);
+        let augmentor = PipelineAugmentor::new(mode, steps, weights)?;
+        Ok(RustPipelineApiClass {
+            augmentor,
+            tokenizer: create_tokenizer(&tokenizer, token_pattern)?,
+            protected_spans: Arc::new(create_protected_spans(
+                protected_detectors,
+                protected_patterns,
+            )?),
+            seed_source: SeedSource::new(seed),
+            config,
+        })
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl RustPipelineApiClass {
+    #[new]
+    #[pyo3(signature = (
+        steps, mode, weights,
+        tokenizer, token_pattern,
+        protected_detectors, protected_patterns, seed)
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        steps: Vec<&PyAny>,
+        mode: String,
+        weights: Option<Vec<f64>>,
+        tokenizer: String,
+        token_pattern: Option<String>,
+        protected_detectors: Option<Vec<String>>,
+        protected_patterns: Option<Vec<String>>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let (steps, step_configs) = steps
+            .into_iter()
+            .map(AugmenterConfig::from_api_object)
+            .collect::<PyResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let config = PipelineConfig {
+            steps: step_configs,
+            mode,
+            weights,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        };
+        Self::with_steps(steps, config).map_err(value_error)
+    }
+
+    #[pyo3(name = "get_config", signature = (format="json"))]
+    fn dump_config(&self, format: &str) -> PyResult<String> {
+        ConfigFormat::from_name(format)
+            .and_then(|format| AugmenterConfig::Pipeline(self.get_config()).dump(format))
+            .map_err(value_error)
+    }
+
+    #[pyo3(signature = (input_string, n, seed=None))]
+    fn augment_string_single_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_single_thread(self, input_string, n, seed)
+    }
+
+    #[pyo3(signature = (input_string, n, n_threads, seed=None))]
+    fn augment_string_multi_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_multi_thread(self, input_string, n, n_threads, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_list_single_thread(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_single_thread(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (input_list, n_threads, seed=None))]
+    fn augment_list_multi_thread(
+        &self,
+        input_list: Vec<String>,
+        n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_multi_thread(self, input_list, n_threads, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_list_with_changed_count(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, usize)> {
+        RustBaseApiClass::augment_list_with_changed_count(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_with_alignment(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, Vec<SpanAlignment>)> {
+        RustBaseApiClass::augment_with_alignment(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (inputs, default_label, seed=None))]
+    fn augment_labeled(
+        &self,
+        inputs: Vec<LabeledTokens>,
+        default_label: String,
+        seed: Option<u64>,
+    ) -> PyResult<Vec<LabeledTokens>> {
+        RustBaseApiClass::augment_labeled(self, inputs, default_label, seed).map_err(value_error)
+    }
+}
+
+impl RustBaseApiClass<PipelineAugmentor, EmptyModel> for RustPipelineApiClass {
+    fn create_augmentor_instance(&self) -> PipelineAugmentor {
+        self.augmentor.clone()
+    }
+
+    fn get_tokenizer(&self) -> Arc<dyn Tokenizer> {
+        Arc::clone(&self.tokenizer)
+    }
+
+    fn get_protected_spans(&self) -> Arc<Option<ProtectedSpans>> {
+        Arc::clone(&self.protected_spans)
+    }
+
+    fn get_seed_source(&self) -> &SeedSource {
+        &self.seed_source
+    }
+
+    fn create_thread_handle_string(
+        &self,
+        input_string_ref: Arc<String>,
+        n_on_thread: usize,
+        first_idx: usize,
+        seed: u64,
+    ) -> thread::JoinHandle<Vec<String>> {
+        let augmentor = self.create_augmentor_instance();
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
+
+        let thread_handle = thread::spawn(move || {
+            let mut thread_res = Vec::with_capacity(n_on_thread);
+            let mut doc = create_doc(
+                &input_string_ref,
+                arc_tokenizer_ref.as_ref(),
+                &arc_protected_ref,
+            );
+            for idx in first_idx..first_idx + n_on_thread {
+                let mut rng = utils::item_rng(seed, idx);
+                augmentor.augment(&mut doc, &mut rng);
+                thread_res.push(doc.get_augmented_string());
+                doc.set_to_original();
+            }
+            thread_res
+        });
+        thread_handle
+    }
+
+    fn create_thread_handle_list(
+        &self,
+        input_list_ref: Arc<Vec<String>>,
+        left_idx: usize,
+        right_idx: usize,
+        seed: u64,
+    ) -> thread::JoinHandle<Vec<String>> {
+        let augmentor = self.create_augmentor_instance();
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
+
+        let thread_handle = thread::spawn(move || {
+            let mut thread_res = Vec::with_capacity(right_idx - left_idx);
+            // [left_idx..right_idx]
+            for (idx, input) in input_list_ref[left_idx..right_idx].iter().enumerate() {
+                let mut rng = utils::item_rng(seed, left_idx + idx);
+                let mut doc = create_doc(input, arc_tokenizer_ref.as_ref(), &arc_protected_ref);
+                augmentor.augment(&mut doc, &mut rng);
+                thread_res.push(doc.get_augmented_string());
+            }
+            thread_res
+        });
+        thread_handle
+    }
+}
@@ -1,16 +1,62 @@
-use super::RustBaseApiClass;
+use super::{
+    create_action, create_doc, create_protected_spans, create_stopwords, create_tokenizer,
+    ApiResult, RustBaseApiClass, SeedSource,
+};
+#[cfg(feature = "python")]
+use super::{value_error, AugmenterConfig, ConfigFormat, LabeledTokens};
 use crate::aug::character::RandomCharAugmentor;
-use crate::aug::{Action, AugCountParams, BaseAugmentor};
-use crate::doc::Doc;
+use crate::aug::{Action, AugCountParams, BaseAugmentor, Stopwords};
+#[cfg(feature = "python")]
+use crate::doc::SpanAlignment;
+use crate::doc::{ProtectedSpans, Tokenizer};
 use crate::model::character::RandomCharModel;
+use crate::utils;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
-use rand::{rngs::StdRng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+#[cfg(feature = "python")]
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::thread;
 
+/// Config of RustRandomCharApiClass: parameters of its constructor
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RandomCharConfig {
+    pub action: String,
+    pub aug_min_char: Option<usize>,
+    pub aug_max_char: Option<usize>,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub aug_p_char: Option<f32>,
+    pub aug_min_word: Option<usize>,
+    pub aug_max_word: Option<usize>,
+    #[serde(serialize_with = "super::config::serialize_option_f32")]
+    pub aug_p_word: Option<f32>,
+    pub include_upper_case: bool,
+    pub include_lower_case: bool,
+    pub include_numeric: bool,
+    pub include_special_char: bool,
+    pub lang: String,
+    pub stopwords: Option<BTreeSet<String>>,
+    #[serde(default = "super::config::default_stopwords_mode")]
+    pub stopwords_mode: String,
+    pub min_char: Option<usize>,
+    pub swap_mode: String,
+    pub spec_char: Option<String>,
+    pub candidates: Option<Vec<String>>,
+    #[serde(default)]
+    pub use_graphemes: bool,
+    #[serde(default = "super::config::default_tokenizer")]
+    pub tokenizer: String,
+    pub token_pattern: Option<String>,
+    pub protected_detectors: Option<Vec<String>>,
+    pub protected_patterns: Option<Vec<String>>,
+    pub seed: Option<u64>,
+}
+
 /// Api Class to perform RandomChar model augmentations on input
-#[pyclass]
+#[cfg_attr(feature = "python", pyclass)]
 pub struct RustRandomCharApiClass {
     /// Action to augmentation, set of values {'substitute', 'insert', 'swap', 'delete'}
     action: Action,
@@ -21,14 +67,93 @@ pub struct RustRandomCharApiClass {
     /// RandomCharModel
     model: Arc<RandomCharModel>,
     /// Filter, Set of words that cannot be augmented
-    stopwords: Arc<Option<HashSet<String>>>,
+    stopwords: Arc<Option<Stopwords>>,
     /// Filter, do not augment word, if it's lenght less than this value
     min_char: Option<usize>,
     /// Choosen swap strategy
     swapmode: String,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
+    /// Splits input to tokens: 'alphanumeric', 'whitespace', 'regex' or 'unicode'
+    tokenizer: Arc<dyn Tokenizer>,
+    /// Spans of input, that are never augmented: URLs, emails, numbers, regex matches, ...
+    protected_spans: Arc<Option<ProtectedSpans>>,
+    /// Source of base seeds for augmentations
+    seed_source: SeedSource,
+    /// Parameters, the augmenter was created with
+    config: RandomCharConfig,
 }
 
 impl RustRandomCharApiClass {
+    /// Create augmenter from its config
+    pub fn from_config(config: RandomCharConfig) -> ApiResult<Self> {
+        let RandomCharConfig {
+            action,
+            aug_min_char,
+            aug_max_char,
+            aug_p_char,
+            aug_min_word,
+            aug_max_word,
+            aug_p_word,
+            include_upper_case,
+            include_lower_case,
+            include_numeric,
+            include_special_char,
+            lang,
+            stopwords,
+            stopwords_mode,
+            min_char,
+            swap_mode,
+            spec_char,
+            candidates,
+            use_graphemes,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        } = config.clone();
+        let action = create_action(&action, &RandomCharAugmentor::ACTIONS)?;
+        let stopwords = stopwords.map(|words| words.into_iter().collect());
+        let mut model = match candidates {
+            Some(values) => RandomCharModel::from_candidates(values),
+            None => RandomCharModel::new(
+                include_upper_case,
+                include_lower_case,
+                include_special_char,
+                include_numeric,
+                &lang,
+                spec_char,
+            ),
+        };
+        model.load_model();
+        Ok(RustRandomCharApiClass {
+            action,
+            aug_char_params: AugCountParams::new(aug_min_char, aug_max_char, aug_p_char),
+            aug_word_params: AugCountParams::new(aug_min_word, aug_max_word, aug_p_word),
+            model: Arc::new(model),
+            stopwords: Arc::new(create_stopwords(stopwords, &stopwords_mode)?),
+            min_char: min_char,
+            swapmode: swap_mode,
+            use_graphemes,
+            tokenizer: create_tokenizer(&tokenizer, token_pattern)?,
+            protected_spans: Arc::new(create_protected_spans(
+                protected_detectors,
+                protected_patterns,
+            )?),
+            seed_source: SeedSource::new(seed),
+            config,
+        })
+    }
+
+    /// Config of augmenter with the seed, it was created with
+    pub fn get_config(&self) -> RandomCharConfig {
+        RandomCharConfig {
+            seed: Some(self.seed_source.seed()),
+            ..self.config.clone()
+        }
+    }
+
     fn get_aug_char_params(&self) -> AugCountParams {
         self.aug_char_params.clone()
     }
@@ -46,6 +171,7 @@ impl RustRandomCharApiClass {
     }
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl RustRandomCharApiClass {
     #[new]
@@ -54,9 +180,12 @@ impl RustRandomCharApiClass {
         aug_min_word, aug_max_word, aug_p_word,
         include_upper_case, include_lower_case,
         include_numeric, include_special_char, lang,
-        stopwords, min_char, swap_mode, spec_char,
-        candidates)
+        stopwords, stopwords_mode, min_char, swap_mode, spec_char,
+        candidates,
+        use_graphemes, tokenizer, token_pattern,
+        protected_detectors, protected_patterns, seed)
     )]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         action: String,
         aug_min_char: Option<usize>,
@@ -71,60 +200,111 @@ impl RustRandomCharApiClass {
         include_special_char: bool,
         lang: String,
         stopwords: Option<HashSet<String>>,
+        stopwords_mode: String,
         min_char: Option<usize>,
         swap_mode: String,
         spec_char: Option<String>,
         candidates: Option<Vec<String>>,
-    ) -> Self {
-        let mut model = match candidates {
-            Some(values) => RandomCharModel::from_candidates(values),
-            None => RandomCharModel::new(
-                include_upper_case,
-                include_lower_case,
-                include_special_char,
-                include_numeric,
-                &lang,
-                spec_char,
-            ),
-        };
-        model.load_model();
-        let action = match &action[..] {
-            "insert" => Action::Insert,
-            "substitute" => Action::Substitute,
-            "delete" => Action::Delete,
-            "swap" => Action::Swap,
-            _ => Action::Substitute,
-        };
-        RustRandomCharApiClass {
+        use_graphemes: bool,
+        tokenizer: String,
+        token_pattern: Option<String>,
+        protected_detectors: Option<Vec<String>>,
+        protected_patterns: Option<Vec<String>>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        Self::from_config(RandomCharConfig {
             action,
-            aug_char_params: AugCountParams::new(aug_min_char, aug_max_char, aug_p_char),
-            aug_word_params: AugCountParams::new(aug_min_word, aug_max_word, aug_p_word),
-            model: Arc::new(model),
-            stopwords: Arc::new(stopwords),
-            min_char: min_char,
-            swapmode: swap_mode,
-        }
+            aug_min_char,
+            aug_max_char,
+            aug_p_char,
+            aug_min_word,
+            aug_max_word,
+            aug_p_word,
+            include_upper_case,
+            include_lower_case,
+            include_numeric,
+            include_special_char,
+            lang,
+            stopwords: stopwords.map(|words| words.into_iter().collect()),
+            stopwords_mode,
+            min_char,
+            swap_mode,
+            spec_char,
+            candidates,
+            use_graphemes,
+            tokenizer,
+            token_pattern,
+            protected_detectors,
+            protected_patterns,
+            seed,
+        })
+        .map_err(value_error)
+    }
+
+    #[pyo3(name = "get_config", signature = (format="json"))]
+    fn dump_config(&self, format: &str) -> PyResult<String> {
+        ConfigFormat::from_name(format)
+            .and_then(|format| AugmenterConfig::RandomChar(self.get_config()).dump(format))
+            .map_err(value_error)
     }
 
-    fn augment_string_single_thread(&self, input_string: String, n: usize) -> Vec<String> {
-        RustBaseApiClass::augment_string_single_thread(self, input_string, n)
+    #[pyo3(signature = (input_string, n, seed=None))]
+    fn augment_string_single_thread(
+        &self,
+        input_string: String,
+        n: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_single_thread(self, input_string, n, seed)
     }
 
+    #[pyo3(signature = (input_string, n, n_threads, seed=None))]
     fn augment_string_multi_thread(
         &self,
         input_string: String,
         n: usize,
         n_threads: usize,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_string_multi_thread(self, input_string, n, n_threads, seed)
+    }
+
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_list_single_thread(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<String> {
+        RustBaseApiClass::augment_list_single_thread(self, input_list, seed)
+    }
+
+    #[pyo3(signature = (input_list, n_threads, seed=None))]
+    fn augment_list_multi_thread(
+        &self,
+        input_list: Vec<String>,
+        n_threads: usize,
+        seed: Option<u64>,
     ) -> Vec<String> {
-        RustBaseApiClass::augment_string_multi_thread(self, input_string, n, n_threads)
+        RustBaseApiClass::augment_list_multi_thread(self, input_list, n_threads, seed)
     }
 
-    fn augment_list_single_thread(&self, input_list: Vec<String>) -> Vec<String> {
-        RustBaseApiClass::augment_list_single_thread(self, input_list)
+    #[pyo3(signature = (input_list, seed=None))]
+    fn augment_with_alignment(
+        &self,
+        input_list: Vec<String>,
+        seed: Option<u64>,
+    ) -> Vec<(String, Vec<SpanAlignment>)> {
+        RustBaseApiClass::augment_with_alignment(self, input_list, seed)
     }
 
-    fn augment_list_multi_thread(&self, input_list: Vec<String>, n_threads: usize) -> Vec<String> {
-        RustBaseApiClass::augment_list_multi_thread(self, input_list, n_threads)
+    #[pyo3(signature = (inputs, default_label, seed=None))]
+    fn augment_labeled(
+        &self,
+        inputs: Vec<LabeledTokens>,
+        default_label: String,
+        seed: Option<u64>,
+    ) -> PyResult<Vec<LabeledTokens>> {
+        RustBaseApiClass::augment_labeled(self, inputs, default_label, seed).map_err(value_error)
     }
 }
 
@@ -138,13 +318,28 @@ impl RustBaseApiClass<RandomCharAugmentor, RandomCharModel> for RustRandomCharAp
             Arc::clone(&self.model),
             Arc::clone(&self.stopwords),
             self.get_swapmode(),
+            self.use_graphemes,
         )
     }
 
+    fn get_tokenizer(&self) -> Arc<dyn Tokenizer> {
+        Arc::clone(&self.tokenizer)
+    }
+
+    fn get_protected_spans(&self) -> Arc<Option<ProtectedSpans>> {
+        Arc::clone(&self.protected_spans)
+    }
+
+    fn get_seed_source(&self) -> &SeedSource {
+        &self.seed_source
+    }
+
     fn create_thread_handle_string(
         &self,
         input_string_ref: Arc<String>,
         n_on_thread: usize,
+        first_idx: usize,
+        seed: u64,
     ) -> thread::JoinHandle<Vec<String>> {
         let action_cloned = self.action.clone();
         let aug_params_char_cloned = self.get_aug_char_params();
@@ -153,11 +348,17 @@ impl RustBaseApiClass<RandomCharAugmentor, RandomCharModel> for RustRandomCharAp
         let arc_model_ref = Arc::clone(&self.model);
         let arc_stopword_ref = Arc::clone(&self.stopwords);
         let swapmode_cloned = self.get_swapmode();
+        let use_graphemes_cloned = self.use_graphemes;
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
 
         let thread_handle = thread::spawn(move || {
-            let mut rng: StdRng = SeedableRng::from_entropy();
             let mut thread_res = Vec::with_capacity(n_on_thread);
-            let mut doc = Doc::from_arc(input_string_ref);
+            let mut doc = create_doc(
+                &input_string_ref,
+                arc_tokenizer_ref.as_ref(),
+                &arc_protected_ref,
+            );
             let augmentor = RandomCharAugmentor::new(
                 action_cloned,
                 aug_params_char_cloned,
@@ -166,8 +367,10 @@ impl RustBaseApiClass<RandomCharAugmentor, RandomCharModel> for RustRandomCharAp
                 arc_model_ref,
                 arc_stopword_ref,
                 swapmode_cloned,
+                use_graphemes_cloned,
             );
-            for _ in 0..n_on_thread {
+            for idx in first_idx..first_idx + n_on_thread {
+                let mut rng = utils::item_rng(seed, idx);
                 augmentor.augment(&mut doc, &mut rng);
                 thread_res.push(doc.get_augmented_string());
                 doc.set_to_original();
@@ -182,6 +385,7 @@ impl RustBaseApiClass<RandomCharAugmentor, RandomCharModel> for RustRandomCharAp
         input_list_ref: Arc<Vec<String>>,
         left_idx: usize,
         right_idx: usize,
+        seed: u64,
     ) -> thread::JoinHandle<Vec<String>> {
         let action_cloned = self.action.clone();
         let aug_params_char_cloned = self.get_aug_char_params();
@@ -190,9 +394,11 @@ impl RustBaseApiClass<RandomCharAugmentor, RandomCharModel> for RustRandomCharAp
         let arc_model_ref = Arc::clone(&self.model);
         let arc_stopword_ref = Arc::clone(&self.stopwords);
         let swapmode_cloned = self.get_swapmode();
+        let use_graphemes_cloned = self.use_graphemes;
+        let arc_tokenizer_ref = self.get_tokenizer();
+        let arc_protected_ref = self.get_protected_spans();
 
         let thread_handle = thread::spawn(move || {
-            let mut rng: StdRng = SeedableRng::from_entropy();
             let mut thread_res = Vec::with_capacity(right_idx - left_idx);
             let augmentor = RandomCharAugmentor::new(
                 action_cloned,
@@ -202,10 +408,12 @@ impl RustBaseApiClass<RandomCharAugmentor, RandomCharModel> for RustRandomCharAp
                 arc_model_ref,
                 arc_stopword_ref,
                 swapmode_cloned,
+                use_graphemes_cloned,
             );
             // [left_idx..right_idx]
-            for input in &input_list_ref.as_ref()[left_idx..right_idx] {
-                let mut doc = Doc::new(input);
+            for (idx, input) in input_list_ref[left_idx..right_idx].iter().enumerate() {
+                let mut rng = utils::item_rng(seed, left_idx + idx);
+                let mut doc = create_doc(input, arc_tokenizer_ref.as_ref(), &arc_protected_ref);
                 augmentor.augment(&mut doc, &mut rng);
                 thread_res.push(doc.get_augmented_string());
             }
@@ -1,7 +1,13 @@
 mod aug_count_params;
 mod base;
 pub mod character;
+#[cfg(test)]
+mod determinism;
+mod pipeline;
+mod stopwords;
 pub mod word;
 
 pub use aug_count_params::AugCountParams;
 pub use base::{Action, BaseAugmentor};
+pub use pipeline::{DocAugmentor, PipelineAugmentor, PipelineMode};
+pub use stopwords::{Stopwords, StopwordsMode};
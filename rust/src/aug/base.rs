@@ -1,18 +1,45 @@
 use rand::prelude::IteratorRandom;
 use rand::rngs::StdRng;
-use std::collections::HashSet;
 
-use super::AugCountParams;
+use super::{AugCountParams, Stopwords};
 use crate::doc::{Doc, TokenHandler};
 use crate::model::BaseModel;
 
 /// Actions enum - what we can do with a data to create augmentions
-#[derive(Clone, Copy)]
+///
+/// Not every augmentor supports every action
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Action {
     Insert,
     Substitute,
     Delete,
     Swap,
+    Split,
+}
+
+impl Action {
+    /// Action by its name: 'insert', 'substitute', 'delete', 'swap' or 'split'
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "insert" => Some(Action::Insert),
+            "substitute" => Some(Action::Substitute),
+            "delete" => Some(Action::Delete),
+            "swap" => Some(Action::Swap),
+            "split" => Some(Action::Split),
+            _ => None,
+        }
+    }
+
+    /// Name of action, as it's passed from python side
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Insert => "insert",
+            Action::Substitute => "substitute",
+            Action::Delete => "delete",
+            Action::Swap => "swap",
+            Action::Split => "split",
+        }
+    }
 }
 
 /// Base augmentors functionality
@@ -30,12 +57,12 @@ where
         None
     }
     fn get_model(&self) -> &T;
-    fn get_stopwords(&self) -> Option<&HashSet<String>>;
+    fn get_stopwords(&self) -> Option<&Stopwords>;
     fn get_use_special_chars(&self) -> bool {
         false
     }
 
-    /// Apply stopwords, min_char and model filtration to the input data
+    /// Apply protected spans, stopwords, min_char and model filtration to the input data
     /// Returns filtered vector of tuples (original_index, element)
     fn get_filtered_word_tokens<'a>(&self, doc: &'a mut Doc) -> Vec<(usize, &'a mut TokenHandler)> {
         let word_tokens = doc.get_word_tokens_with_indexes(self.get_use_special_chars());
@@ -47,14 +74,17 @@ where
         let mut filtered = Vec::with_capacity(word_tokens.len());
 
         for (idx, handler) in word_tokens {
-            let orig_token = handler.get_original().token();
+            if handler.is_protected() {
+                continue;
+            }
+            let orig_token = handler.get_current().token();
             if use_model_to_filtration {
                 if !model.key_exists(orig_token) {
                     continue;
                 }
             }
             if let Some(min_char_len) = min_chars {
-                let token_len = handler.get_original().utf8_len();
+                let token_len = handler.get_current().utf8_len();
                 if token_len < min_char_len {
                     continue;
                 }
@@ -96,6 +126,7 @@ where
 #[cfg(test)]
 mod tests {
     use rand::SeedableRng;
+    use std::collections::HashSet;
 
     use super::*;
     use crate::doc::*;
@@ -123,7 +154,7 @@ mod tests {
         aug_params_word: AugCountParams,
         min_chars: Option<usize>,
         model: &'a MockModel,
-        stopwords: Option<&'a HashSet<String>>,
+        stopwords: Option<&'a Stopwords>,
         use_special_chars: bool,
         use_model_to_filtration: bool,
     }
@@ -145,7 +176,7 @@ mod tests {
         fn get_model(&self) -> &MockModel {
             self.model
         }
-        fn get_stopwords(&self) -> Option<&HashSet<String>> {
+        fn get_stopwords(&self) -> Option<&Stopwords> {
             self.stopwords
         }
         fn get_use_special_chars(&self) -> bool {
@@ -209,11 +240,38 @@ mod tests {
         assert_eq!(result.len(), 2);
     }
 
+    #[test]
+    fn test_filter_protected_spans() {
+        let mut doc = Doc::new(&String::from("Mail me@site.org or #call 911"));
+        let protected = ProtectedSpans::new(
+            &[
+                String::from("email"),
+                String::from("hashtag"),
+                String::from("number"),
+            ],
+            &[],
+        )
+        .unwrap();
+        doc.protect_spans(&protected);
+        let model = MockModel::new(vec![]);
+        let mock_aug = MockAugmentor {
+            aug_params_word: AugCountParams::new(None, None, None),
+            min_chars: None,
+            model: &model,
+            stopwords: None,
+            use_special_chars: true,
+            use_model_to_filtration: false,
+        };
+        let result = mock_aug.get_filtered_word_tokens(&mut doc);
+        let tokens: Vec<&String> = result.iter().map(|x| x.1.get_current().token()).collect();
+        assert_eq!(tokens, vec!["Mail", "or"]);
+    }
+
     #[test]
     fn test_filter_by_min_char_and_stopwords() {
         let mut doc = Doc::new(&String::from("My !example ! string!"));
         let model = MockModel::new(vec![]);
-        let stopwords = HashSet::from([String::from("example")]);
+        let stopwords = Stopwords::from(HashSet::from([String::from("example")]));
         let mock_aug = MockAugmentor {
             aug_params_word: AugCountParams::new(None, None, None),
             min_chars: Some(2),
@@ -232,7 +290,7 @@ mod tests {
     fn test_filter_by_min_char_and_stopwords_and_empty_model() {
         let mut doc = Doc::new(&String::from("My !example ! string!"));
         let model = MockModel::new(vec![]);
-        let stopwords = HashSet::from([String::from("example")]);
+        let stopwords = Stopwords::from(HashSet::from([String::from("example")]));
         let mock_aug = MockAugmentor {
             aug_params_word: AugCountParams::new(None, None, None),
             min_chars: Some(2),
@@ -249,7 +307,7 @@ mod tests {
     fn test_filter_by_min_char_and_stopwords_and_non_empty_model() {
         let mut doc = Doc::new(&String::from("My !example ! string!"));
         let model = MockModel::new(vec![String::from("string")]);
-        let stopwords = HashSet::from([String::from("example")]);
+        let stopwords = Stopwords::from(HashSet::from([String::from("example")]));
         let mock_aug = MockAugmentor {
             aug_params_word: AugCountParams::new(None, None, None),
             min_chars: Some(2),
@@ -268,7 +326,7 @@ mod tests {
     fn test_sample_some_data() {
         let mut doc = Doc::new(&String::from("My !example ! string!"));
         let model = MockModel::new(vec![]);
-        let stopwords = HashSet::from([String::from("example")]);
+        let stopwords = Stopwords::from(HashSet::from([String::from("example")]));
         let mut rng: StdRng = SeedableRng::from_entropy();
 
         let mock_aug = MockAugmentor {
@@ -288,7 +346,7 @@ mod tests {
     fn test_sample_full_data() {
         let mut doc = Doc::new(&String::from("My !example ! string!"));
         let model = MockModel::new(vec![]);
-        let stopwords = HashSet::from([String::from("example")]);
+        let stopwords = Stopwords::from(HashSet::from([String::from("example")]));
         let mut rng: StdRng = SeedableRng::from_entropy();
 
         let mock_aug = MockAugmentor {
@@ -308,7 +366,7 @@ mod tests {
     fn test_sample_zero_data() {
         let mut doc = Doc::new(&String::from("My !example ! string!"));
         let model = MockModel::new(vec![]);
-        let stopwords = HashSet::from([String::from("example")]);
+        let stopwords = Stopwords::from(HashSet::from([String::from("example")]));
         let mut rng: StdRng = SeedableRng::from_entropy();
 
         let mock_aug = MockAugmentor {
@@ -1,9 +1,13 @@
 mod base;
+mod confusion;
+mod homoglyph;
 mod keyboard;
 mod ocr;
 mod random;
 
 pub use base::CharacterAugmentor;
+pub use confusion::ConfusionAugmentor;
+pub use homoglyph::HomoglyphAugmentor;
 pub use keyboard::KeyboardAugmentor;
 pub use ocr::OcrAugmentor;
 pub use random::RandomCharAugmentor;
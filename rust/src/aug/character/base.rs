@@ -3,6 +3,7 @@ use crate::doc::{Doc, Token, TokenType};
 use crate::model::character::CharacterModel;
 use rand::prelude::IteratorRandom;
 use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 use std::collections::HashSet;
 
 /// Base character augmentors functionality
@@ -11,18 +12,19 @@ where
     T: CharacterModel,
 {
     fn get_aug_params_char(&self) -> &AugCountParams;
+    /// Operate on extended grapheme clusters instead of chars
+    fn get_use_graphemes(&self) -> bool;
 
     /// Create sample of chars to augmentation from chosen word
     ///
-    /// Before sampling, we check if the symbol exists in the model
+    /// Before sampling, we check if the symbol exists in the model.
+    /// Indexes are positions of chars (or grapheme clusters, if `get_use_graphemes`)
     fn sample_chars_to_aug(&self, token: &Token, rng: &mut StdRng) -> HashSet<usize> {
-        let mut char_indexes: Vec<usize> = Vec::with_capacity(token.utf8_len());
-        let aug_cnt = self
-            .get_aug_params_char()
-            .calculate_aug_cnt(token.utf8_len());
-        for (idx, char) in token.token().chars().enumerate() {
-            let key = char.to_string();
-            let contains = self.get_model().key_exists(&key);
+        let chars = token.split_chars(self.get_use_graphemes());
+        let mut char_indexes: Vec<usize> = Vec::with_capacity(chars.len());
+        let aug_cnt = self.get_aug_params_char().calculate_aug_cnt(chars.len());
+        for (idx, key) in chars.into_iter().enumerate() {
+            let contains = self.get_model().key_exists(key);
             if contains {
                 char_indexes.push(idx);
             }
@@ -38,26 +40,36 @@ where
         HashSet::from_iter(sampled)
     }
 
+    /// Draw one of model predictions for char
+    ///
+    /// If model has weights, predictions are drawn proportionally to them
+    fn choose_prediction(&self, ch_str: &str, rng: &mut StdRng) -> Option<String> {
+        let predicted = self.get_model().predict(ch_str)?;
+        let weights = self.get_model().get_weights().and_then(|w| w.get(ch_str));
+        let chosen = match weights {
+            Some(weights) => predicted
+                .choose_weighted(rng, |x| weights.get(x).copied().unwrap_or(0.0))
+                .ok(),
+            None => predicted.iter().choose(rng),
+        };
+        chosen.cloned()
+    }
+
     /// Closure performed for each character in the word
     /// to make predictions for characters that are sampled
     fn predict_char(
         &self,
         idx: usize,
-        ch: char,
+        ch: &str,
         char_idxs: &HashSet<usize>,
         rng: &mut StdRng,
     ) -> String {
-        let ch_str = ch.to_string();
         if char_idxs.contains(&idx) {
-            let predict = self.get_model().predict(&ch_str);
-            if let Some(predicted) = predict {
-                let replacer = predicted.into_iter().choose(rng);
-                if let Some(value) = replacer {
-                    return value.clone();
-                }
+            if let Some(value) = self.choose_prediction(ch, rng) {
+                return value;
             }
         }
-        ch_str
+        ch.to_string()
     }
 
     /// Default substitute method to char models
@@ -65,15 +77,15 @@ where
         let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
         let mut change_seq = 0;
         for (_, a_token) in aug_tokens {
-            let original_token = a_token.get_original();
+            let original_token = a_token.get_current();
             let aug_chars_indexes = self.sample_chars_to_aug(original_token, rng);
             if aug_chars_indexes.len() == 0 {
                 continue;
             }
             let mut result = String::with_capacity(original_token.byte_len() * 2);
             original_token
-                .token()
-                .chars()
+                .split_chars(self.get_use_graphemes())
+                .into_iter()
                 .enumerate()
                 .map(|(idx, ch)| self.predict_char(idx, ch, &aug_chars_indexes, rng))
                 .for_each(|x| result.push_str(&x));
@@ -83,18 +95,90 @@ where
         }
         doc.set_change_count(change_seq);
     }
+
+    /// Closure performed for each character in the word
+    /// to make predictions for characters that are sampled
+    /// and insert predicted char before the original one
+    fn insert_predicted_char(
+        &self,
+        idx: usize,
+        ch: &str,
+        char_idxs: &HashSet<usize>,
+        rng: &mut StdRng,
+    ) -> String {
+        if char_idxs.contains(&idx) {
+            if let Some(mut value) = self.choose_prediction(ch, rng) {
+                value.push_str(ch);
+                return value;
+            }
+        }
+        ch.to_string()
+    }
+
+    /// Default insert method to char models
+    fn insert(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
+        let mut change_seq = 0;
+        for (_, a_token) in aug_tokens {
+            let original_token = a_token.get_current();
+            let aug_chars_indexes = self.sample_chars_to_aug(original_token, rng);
+            if aug_chars_indexes.is_empty() {
+                continue;
+            }
+            let mut result = String::with_capacity(original_token.byte_len() * 2);
+            original_token
+                .split_chars(self.get_use_graphemes())
+                .into_iter()
+                .enumerate()
+                .map(|(idx, ch)| self.insert_predicted_char(idx, ch, &aug_chars_indexes, rng))
+                .for_each(|x| result.push_str(&x));
+            result.shrink_to_fit();
+            a_token.change(TokenType::WordToken, result);
+            change_seq += 1;
+        }
+        doc.set_change_count(change_seq);
+    }
+
+    /// Default delete method to char models
+    ///
+    /// Only chars, that exist in the model, can be deleted
+    fn delete(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
+        let mut change_seq = 0;
+        for (_, a_token) in aug_tokens {
+            let original_token = a_token.get_current();
+            let aug_chars_indexes = self.sample_chars_to_aug(original_token, rng);
+            if aug_chars_indexes.is_empty() {
+                continue;
+            }
+            let mut result = String::with_capacity(original_token.byte_len());
+            original_token
+                .split_chars(self.get_use_graphemes())
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, _)| !aug_chars_indexes.contains(idx))
+                .for_each(|(_, ch)| result.push_str(ch));
+            result.shrink_to_fit();
+            a_token.change(TokenType::WordToken, result);
+            change_seq += 1;
+        }
+        doc.set_change_count(change_seq);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::super::Action;
     use super::*;
-    use crate::model::{BaseModel, Mapping};
+    use crate::aug::Stopwords;
+    use crate::doc::UnicodeWordTokenizer;
+    use crate::model::{BaseModel, Mapping, WeightedMapping};
     use crate::utils;
     use rand::SeedableRng;
     use std::collections::{HashMap, HashSet};
     struct MockModel {
         mapping: Option<Mapping>,
+        weights: Option<WeightedMapping>,
     }
     impl MockModel {
         fn new() -> Self {
@@ -126,6 +210,7 @@ mod tests {
             ]);
             MockModel {
                 mapping: Some(model),
+                weights: None,
             }
         }
     }
@@ -133,6 +218,9 @@ mod tests {
         fn get_mapping(&self) -> Option<&Mapping> {
             self.mapping.as_ref()
         }
+        fn get_weights(&self) -> Option<&WeightedMapping> {
+            self.weights.as_ref()
+        }
     }
     impl CharacterModel for MockModel {}
 
@@ -140,7 +228,8 @@ mod tests {
         aug_params_char: AugCountParams,
         aug_params_word: AugCountParams,
         model: MockModel,
-        stopwords: Option<&'a HashSet<String>>,
+        stopwords: Option<&'a Stopwords>,
+        use_graphemes: bool,
     }
     impl<'a> BaseAugmentor<MockModel> for MockAugmentor<'a> {
         fn augment(&self, _: &mut Doc, _: &mut StdRng) -> () {}
@@ -154,7 +243,7 @@ mod tests {
         fn get_model(&self) -> &MockModel {
             &self.model
         }
-        fn get_stopwords(&self) -> Option<&HashSet<String>> {
+        fn get_stopwords(&self) -> Option<&Stopwords> {
             self.stopwords
         }
     }
@@ -162,6 +251,9 @@ mod tests {
         fn get_aug_params_char(&self) -> &AugCountParams {
             &self.aug_params_char
         }
+        fn get_use_graphemes(&self) -> bool {
+            self.use_graphemes
+        }
     }
 
     #[test]
@@ -172,6 +264,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("Qvqv"));
@@ -187,6 +280,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("агсагсагс"));
@@ -203,6 +297,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("vavava"));
@@ -220,6 +315,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("Авиастроение"));
@@ -235,6 +331,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("vavava"));
@@ -250,6 +347,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("none"));
@@ -265,6 +363,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("агс"));
@@ -280,6 +379,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let mut rng: StdRng = SeedableRng::from_entropy();
         let token = Token::new(TokenType::WordToken, String::from("ноль"));
@@ -295,10 +395,11 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let char_idxs: HashSet<usize> = HashSet::from([2, 3]);
         let mut rng: StdRng = SeedableRng::from_entropy();
-        let result = mock_aug.predict_char(0, 'м', &char_idxs, &mut rng);
+        let result = mock_aug.predict_char(0, "м", &char_idxs, &mut rng);
         assert_eq!(result, String::from("м"));
     }
 
@@ -310,10 +411,11 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let char_idxs: HashSet<usize> = HashSet::from([2, 3]);
         let mut rng: StdRng = SeedableRng::from_entropy();
-        let result = mock_aug.predict_char(0, 'г', &char_idxs, &mut rng);
+        let result = mock_aug.predict_char(0, "г", &char_idxs, &mut rng);
         assert_eq!(result, String::from("г"));
     }
 
@@ -325,10 +427,11 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let char_idxs: HashSet<usize> = HashSet::from([2, 3]);
         let mut rng: StdRng = SeedableRng::from_entropy();
-        let result = mock_aug.predict_char(3, 'к', &char_idxs, &mut rng);
+        let result = mock_aug.predict_char(3, "к", &char_idxs, &mut rng);
         assert_eq!(result, String::from("к"));
     }
 
@@ -340,13 +443,70 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let char_idxs: HashSet<usize> = HashSet::from([2, 3]);
         let mut rng: StdRng = SeedableRng::from_entropy();
-        let result = mock_aug.predict_char(3, 'А', &char_idxs, &mut rng);
+        let result = mock_aug.predict_char(3, "А", &char_idxs, &mut rng);
         assert!((result == String::from("Х")) | (result == String::from("Ш")));
     }
 
+    #[test]
+    fn test_predict_char_weighted() {
+        let mut model = MockModel::new();
+        model.weights = Some(HashMap::from([(
+            String::from("А"),
+            HashMap::from([(String::from("Х"), 0.9), (String::from("Ш"), 0.1)]),
+        )]));
+        let mock_aug = MockAugmentor {
+            aug_params_char: AugCountParams::new(None, None, None),
+            aug_params_word: AugCountParams::new(None, None, None),
+            model,
+            stopwords: None,
+            use_graphemes: false,
+        };
+        let char_idxs: HashSet<usize> = HashSet::from([0]);
+        let mut rng: StdRng = SeedableRng::seed_from_u64(42);
+        let mut x_count = 0;
+        for _ in 0..1000 {
+            if mock_aug.predict_char(0, "А", &char_idxs, &mut rng) == "Х" {
+                x_count += 1;
+            }
+        }
+        assert!((850..950).contains(&x_count));
+
+        // Chars without weights are still drawn uniformly
+        let result = mock_aug.predict_char(0, "а", &char_idxs, &mut rng);
+        assert!((result == "О") | (result == "0"));
+    }
+
+    #[test]
+    fn test_graphemes_are_not_split() {
+        let input_string = String::from("vv\u{301}v");
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        let cases = [
+            (false, HashSet::from([0, 1, 3]), "\u{301}"),
+            (true, HashSet::from([0, 2]), "v\u{301}"),
+        ];
+        for (use_graphemes, expected_sampled, expected) in cases {
+            let mock_aug = MockAugmentor {
+                aug_params_char: AugCountParams::new(None, Some(10), Some(1.0)),
+                aug_params_word: AugCountParams::new(None, None, Some(1.0)),
+                model: MockModel::new(),
+                stopwords: None,
+                use_graphemes,
+            };
+            let token = Token::new(TokenType::WordToken, input_string.clone());
+            let sampled = mock_aug.sample_chars_to_aug(&token, &mut rng);
+            assert_eq!(sampled, expected_sampled);
+
+            // Default tokenizer splits combining accent from the word
+            let mut doc = Doc::new_with_tokenizer(&input_string, &UnicodeWordTokenizer);
+            mock_aug.delete(&mut doc, &mut rng);
+            assert_eq!(doc.get_augmented_string(), expected);
+        }
+    }
+
     #[test]
     fn test_substitute_word_non_sampled() {
         let model = MockModel::new();
@@ -355,6 +515,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, Some(0.0)),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let input_string = String::from("Пример строки для аугментации");
         let mut doc = Doc::new(&input_string);
@@ -372,6 +533,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let input_string = String::from("Пример строки для аугментации");
         let mut doc = Doc::new(&input_string);
@@ -389,6 +551,7 @@ mod tests {
             aug_params_word: AugCountParams::new(None, None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let input_string = String::from("Пример ещё один");
         let mut doc = Doc::new(&input_string);
@@ -406,6 +569,7 @@ mod tests {
             aug_params_word: AugCountParams::new(Some(2), None, None),
             model: model,
             stopwords: None,
+            use_graphemes: false,
         };
         let input_string = String::from("Апельсин гора стакан");
         let mut doc = Doc::new(&input_string);
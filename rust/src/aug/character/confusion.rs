@@ -0,0 +1,112 @@
+use super::super::{Action, AugCountParams, BaseAugmentor, Stopwords};
+use super::CharacterAugmentor;
+use crate::model::character::ConfusionModel;
+use std::sync::Arc;
+
+/// Augmentor, which performs ConfusionModel on text
+///
+/// Learned insertions and deletions are encoded in mapping values, so only substitution is needed
+pub struct ConfusionAugmentor {
+    /// Parameteres to calculate number of chars that will be augmented in single word
+    aug_params_char: AugCountParams,
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// ConfusionModel
+    model: Arc<ConfusionModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
+}
+
+impl ConfusionAugmentor {
+    pub fn new(
+        aug_params_char: AugCountParams,
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<ConfusionModel>,
+        stopwords: Arc<Option<Stopwords>>,
+        use_graphemes: bool,
+    ) -> Self {
+        ConfusionAugmentor {
+            aug_params_char,
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+            use_graphemes,
+        }
+    }
+}
+
+impl BaseAugmentor<ConfusionModel> for ConfusionAugmentor {
+    fn augment(&self, doc: &mut crate::doc::Doc, rng: &mut rand::rngs::StdRng) {
+        self.substitute(doc, rng)
+    }
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+    fn get_model(&self) -> &ConfusionModel {
+        self.model.as_ref()
+    }
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+impl CharacterAugmentor<ConfusionModel> for ConfusionAugmentor {
+    fn get_aug_params_char(&self) -> &AugCountParams {
+        &self.aug_params_char
+    }
+
+    fn get_use_graphemes(&self) -> bool {
+        self.use_graphemes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::Doc;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_substitute() {
+        let model = ConfusionModel::train(
+            &[
+                (String::from("all"), String::from("a1")),
+                (String::from("mom"), String::from("rnom")),
+            ],
+            1,
+        );
+        let augmentor = ConfusionAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(model),
+            Arc::new(Some(Stopwords::from(HashSet::from([String::from("mall")])))),
+            false,
+        );
+        let input_string = String::from("ball mall moon");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        augmentor.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 2);
+        assert!(result.starts_with("b"));
+        assert!(result.contains(" mall "));
+        assert!(result.ends_with("rnoon"));
+        let first_word = result.split(' ').next().unwrap();
+        assert!(["ba11", "ba1", "ba"].contains(&first_word));
+    }
+}
@@ -0,0 +1,113 @@
+use super::super::{Action, AugCountParams, BaseAugmentor, Stopwords};
+use super::CharacterAugmentor;
+use crate::model::character::HomoglyphModel;
+use std::sync::Arc;
+
+/// Augmentor, which performs HomoglyphModel on text
+pub struct HomoglyphAugmentor {
+    /// Parameteres to calculate number of chars that will be augmented in single word
+    aug_params_char: AugCountParams,
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// HomoglyphModel
+    model: Arc<HomoglyphModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
+}
+
+impl HomoglyphAugmentor {
+    pub fn new(
+        aug_params_char: AugCountParams,
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<HomoglyphModel>,
+        stopwords: Arc<Option<Stopwords>>,
+        use_graphemes: bool,
+    ) -> Self {
+        HomoglyphAugmentor {
+            aug_params_char,
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+            use_graphemes,
+        }
+    }
+}
+
+impl BaseAugmentor<HomoglyphModel> for HomoglyphAugmentor {
+    fn augment(&self, doc: &mut crate::doc::Doc, rng: &mut rand::rngs::StdRng) {
+        self.substitute(doc, rng)
+    }
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+    fn get_model(&self) -> &HomoglyphModel {
+        self.model.as_ref()
+    }
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+impl CharacterAugmentor<HomoglyphModel> for HomoglyphAugmentor {
+    fn get_aug_params_char(&self) -> &AugCountParams {
+        &self.aug_params_char
+    }
+
+    fn get_use_graphemes(&self) -> bool {
+        self.use_graphemes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::Doc;
+    use crate::utils;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_substitute() {
+        let mut model = HomoglyphModel::new(String::from("test_res/homoglyph_en.json"));
+        model.load_model().unwrap();
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+            "apple",
+        )]))));
+        let augmentor = HomoglyphAugmentor::new(
+            AugCountParams::new(Some(1), Some(5), Some(1.0)),
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(model),
+            Arc::clone(&stopwords),
+            false,
+        );
+        let input_string = String::from("apple pop sea");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        augmentor.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(
+            utils::get_chars_len(&result),
+            utils::get_chars_len(&input_string)
+        );
+        assert!(result.starts_with("apple "));
+        assert!(result.ends_with("sеа"));
+        let second_word: Vec<char> = result.split(' ').nth(1).unwrap().chars().collect();
+        assert!(second_word[0] == 'р');
+        assert!((second_word[1] == 'о') | (second_word[1] == 'ο'));
+        assert!(second_word[2] == 'р');
+    }
+}
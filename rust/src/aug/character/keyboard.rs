@@ -1,11 +1,12 @@
-use super::super::{Action, AugCountParams, BaseAugmentor};
+use super::super::{Action, AugCountParams, BaseAugmentor, Stopwords};
 use super::CharacterAugmentor;
 use crate::model::character::KeyboardModel;
-use std::collections::HashSet;
 use std::sync::Arc;
 
 /// Augmentor, which performs KeyboardModel on text
 pub struct KeyboardAugmentor {
+    /// Action to augmentation, set of values {'substitute', 'insert', 'delete'}
+    action: Action,
     /// Parameteres to calculate number of chars that will be augmented in single word
     aug_params_char: AugCountParams,
     /// Parameteres to calculate number of words that will be augmented
@@ -15,37 +16,57 @@ pub struct KeyboardAugmentor {
     /// KeyboardModel
     model: Arc<KeyboardModel>,
     /// Filter, Set of words that cannot be augmented
-    stopwords: Arc<Option<HashSet<String>>>,
+    stopwords: Arc<Option<Stopwords>>,
     /// Flag, if it's true then we can augment special_chars
     use_special_chars: bool,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
 }
 
 impl KeyboardAugmentor {
+    /// Actions, supported by augmentor
+    pub const ACTIONS: [Action; 3] = [Action::Insert, Action::Substitute, Action::Delete];
+
+    /// Panics, if `action` is not one of `ACTIONS`
     pub fn new(
+        action: Action,
         aug_params_char: AugCountParams,
         aug_params_word: AugCountParams,
         min_chars: Option<usize>,
         model: Arc<KeyboardModel>,
-        stopwords: Arc<Option<HashSet<String>>>,
+        stopwords: Arc<Option<Stopwords>>,
+        use_graphemes: bool,
     ) -> Self {
+        assert!(
+            Self::ACTIONS.contains(&action),
+            "KeyboardAugmentor doesn't support action '{}'",
+            action.name()
+        );
         let use_special_chars = model.get_allow_special_char();
         KeyboardAugmentor {
+            action,
             aug_params_char,
             aug_params_word,
             min_chars,
             model,
             stopwords,
             use_special_chars,
+            use_graphemes,
         }
     }
 }
 
 impl BaseAugmentor<KeyboardModel> for KeyboardAugmentor {
     fn augment(&self, doc: &mut crate::doc::Doc, rng: &mut rand::rngs::StdRng) -> () {
-        self.substitute(doc, rng)
+        match self.action {
+            Action::Insert => self.insert(doc, rng),
+            Action::Substitute => self.substitute(doc, rng),
+            Action::Delete => self.delete(doc, rng),
+            _ => unreachable!("rejected on creation"),
+        }
     }
     fn get_action(&self) -> Action {
-        Action::Substitute
+        self.action
     }
     fn get_aug_params_word(&self) -> &AugCountParams {
         &self.aug_params_word
@@ -56,7 +77,7 @@ impl BaseAugmentor<KeyboardModel> for KeyboardAugmentor {
     fn get_model(&self) -> &KeyboardModel {
         self.model.as_ref()
     }
-    fn get_stopwords(&self) -> Option<&HashSet<String>> {
+    fn get_stopwords(&self) -> Option<&Stopwords> {
         self.stopwords.as_ref().as_ref()
     }
     fn get_use_special_chars(&self) -> bool {
@@ -68,15 +89,21 @@ impl CharacterAugmentor<KeyboardModel> for KeyboardAugmentor {
     fn get_aug_params_char(&self) -> &AugCountParams {
         &self.aug_params_char
     }
+
+    fn get_use_graphemes(&self) -> bool {
+        self.use_graphemes
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::doc::Doc;
+    use crate::model::BaseModel;
     use crate::utils;
     use rand::rngs::StdRng;
     use rand::SeedableRng;
+    use std::collections::HashSet;
 
     #[test]
     fn test_substitute_all_false() {
@@ -86,19 +113,21 @@ mod tests {
             false,
             String::from("test_res/keyboard_en.json"),
         );
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("The"),
-        ])));
-        // KeyboardAugmentor::new(aug_params_char, aug_params_word, min_chars, &model, stopwords)
+        ]))));
+        // KeyboardAugmentor::new(action, aug_params_char, aug_params_word, min_chars, &model, stopwords, false)
         let augmentor = KeyboardAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             Some(3),
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
+            false,
         );
         let input_string = String::from("The quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -122,18 +151,20 @@ mod tests {
             false,
             String::from("test_res/keyboard_ru.json"),
         );
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("для"),
             String::from("Юнит"),
-        ])));
+        ]))));
         let augmentor = KeyboardAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             Some(3),
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
+            false,
         );
         let input_string = String::from("Юнит-тест для тестов, тестов");
         let mut doc = Doc::new(&input_string);
@@ -157,18 +188,20 @@ mod tests {
             true,
             String::from("test_res/keyboard_en.json"),
         );
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("the"),
-        ])));
+        ]))));
         let augmentor = KeyboardAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             Some(3),
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
+            false,
         );
         let input_string = String::from("the quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -200,15 +233,19 @@ mod tests {
             true,
             String::from("test_res/keyboard_ru.json"),
         );
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([String::from("Пример")])));
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+            "Пример",
+        )]))));
         let augmentor = KeyboardAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             Some(3),
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
+            false,
         );
         let input_string = String::from("Пример строки для аугментации");
         let mut doc = Doc::new(&input_string);
@@ -239,14 +276,16 @@ mod tests {
             false,
             String::from("test_res/keyboard_en.json"),
         );
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
         let augmentor = KeyboardAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(3), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             None,
             Arc::clone(&arc_model),
             Arc::new(None),
+            false,
         );
         let input_string = String::from("$$$$$$$!$@@$@$$@!!!!");
         let mut doc = Doc::new(&input_string);
@@ -276,14 +315,16 @@ mod tests {
             false,
             String::from("test_res/keyboard_en.json"),
         );
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
         let augmentor = KeyboardAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             None,
             Arc::clone(&arc_model),
             Arc::new(None),
+            false,
         );
         let input_string = String::from("0351368213471238123512409");
         let mut doc = Doc::new(&input_string);
@@ -304,4 +345,78 @@ mod tests {
         }
         assert!(numeric_count < 25)
     }
+
+    #[test]
+    fn test_insert() {
+        let mut model = KeyboardModel::new(
+            false,
+            false,
+            false,
+            String::from("test_res/keyboard_en.json"),
+        );
+        model.load_model().unwrap();
+        let arc_model = Arc::new(model);
+        let augmentor = KeyboardAugmentor::new(
+            Action::Insert,
+            AugCountParams::new(Some(1), Some(1), None),
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::clone(&arc_model),
+            Arc::new(None),
+            false,
+        );
+        let input_string = String::from("hello");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        augmentor.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 1);
+        assert_eq!(utils::get_chars_len(&result), 6);
+
+        // Inserted char is placed before original one and it's neighbour key of it
+        let chars: Vec<char> = result.chars().collect();
+        let inserted_neighbour = (0..chars.len() - 1).any(|idx| {
+            let mut rest = chars.clone();
+            let inserted = rest.remove(idx);
+            let next = rest[idx].to_string();
+            rest.into_iter().collect::<String>() == input_string
+                && arc_model
+                    .predict(&next)
+                    .is_some_and(|x| x.contains(&inserted.to_string()))
+        });
+        assert!(inserted_neighbour);
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut model = KeyboardModel::new(
+            false,
+            false,
+            false,
+            String::from("test_res/keyboard_ru.json"),
+        );
+        model.load_model().unwrap();
+        let arc_model = Arc::new(model);
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from("для")]))));
+        let augmentor = KeyboardAugmentor::new(
+            Action::Delete,
+            AugCountParams::new(Some(2), Some(2), None),
+            AugCountParams::new(None, None, Some(1.0)),
+            Some(3),
+            Arc::clone(&arc_model),
+            Arc::clone(&stopwords),
+            false,
+        );
+        let input_string = String::from("Пример строки для аугментации");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        augmentor.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 3);
+        assert_eq!(
+            utils::get_chars_len(&result),
+            utils::get_chars_len(&input_string) - 6
+        );
+        assert!(result.contains(" для "));
+    }
 }
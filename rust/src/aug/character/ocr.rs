@@ -1,11 +1,12 @@
-use super::super::{Action, AugCountParams, BaseAugmentor};
+use super::super::{Action, AugCountParams, BaseAugmentor, Stopwords};
 use super::CharacterAugmentor;
 use crate::model::character::OcrModel;
-use std::collections::HashSet;
 use std::sync::Arc;
 
 /// Augmentor, which performs OcrModel on text
 pub struct OcrAugmentor {
+    /// Action to augmentation, set of values {'substitute', 'insert', 'delete'}
+    action: Action,
     /// Parameteres to calculate number of chars that will be augmented in single word
     aug_params_char: AugCountParams,
     /// Parameteres to calculate number of words that will be augmented
@@ -15,33 +16,53 @@ pub struct OcrAugmentor {
     /// OcrModel
     model: Arc<OcrModel>,
     /// Filter, Set of words that cannot be augmented
-    stopwords: Arc<Option<HashSet<String>>>,
+    stopwords: Arc<Option<Stopwords>>,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
 }
 
 impl OcrAugmentor {
+    /// Actions, supported by augmentor
+    pub const ACTIONS: [Action; 3] = [Action::Insert, Action::Substitute, Action::Delete];
+
+    /// Panics, if `action` is not one of `ACTIONS`
     pub fn new(
+        action: Action,
         aug_params_char: AugCountParams,
         aug_params_word: AugCountParams,
         min_chars: Option<usize>,
         model: Arc<OcrModel>,
-        stopwords: Arc<Option<HashSet<String>>>,
+        stopwords: Arc<Option<Stopwords>>,
+        use_graphemes: bool,
     ) -> Self {
+        assert!(
+            Self::ACTIONS.contains(&action),
+            "OcrAugmentor doesn't support action '{}'",
+            action.name()
+        );
         OcrAugmentor {
+            action,
             aug_params_char,
             aug_params_word,
             min_chars,
             model,
             stopwords,
+            use_graphemes,
         }
     }
 }
 
 impl BaseAugmentor<OcrModel> for OcrAugmentor {
     fn augment(&self, doc: &mut crate::doc::Doc, rng: &mut rand::rngs::StdRng) -> () {
-        self.substitute(doc, rng)
+        match self.action {
+            Action::Insert => self.insert(doc, rng),
+            Action::Substitute => self.substitute(doc, rng),
+            Action::Delete => self.delete(doc, rng),
+            _ => unreachable!("rejected on creation"),
+        }
     }
     fn get_action(&self) -> Action {
-        Action::Substitute
+        self.action
     }
     fn get_aug_params_word(&self) -> &AugCountParams {
         &self.aug_params_word
@@ -52,7 +73,7 @@ impl BaseAugmentor<OcrModel> for OcrAugmentor {
     fn get_model(&self) -> &OcrModel {
         self.model.as_ref()
     }
-    fn get_stopwords(&self) -> Option<&HashSet<String>> {
+    fn get_stopwords(&self) -> Option<&Stopwords> {
         self.stopwords.as_ref().as_ref()
     }
 }
@@ -61,6 +82,10 @@ impl CharacterAugmentor<OcrModel> for OcrAugmentor {
     fn get_aug_params_char(&self) -> &AugCountParams {
         &self.aug_params_char
     }
+
+    fn get_use_graphemes(&self) -> bool {
+        self.use_graphemes
+    }
 }
 
 #[cfg(test)]
@@ -70,22 +95,25 @@ mod tests {
     use crate::utils;
     use rand::rngs::StdRng;
     use rand::SeedableRng;
+    use std::collections::HashSet;
 
     #[test]
     fn test_substitute() {
         let mut model = OcrModel::new(String::from("test_res/ocr_en.json"));
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("The"),
-        ])));
+        ]))));
         let augmentor = OcrAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             Some(3),
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
+            false,
         );
         let input_string = String::from("The quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -104,18 +132,20 @@ mod tests {
     #[test]
     fn test_substitute_cyrillic() {
         let mut model = OcrModel::new(String::from("test_res/ocr_ru.json"));
-        model.load_model();
+        model.load_model().unwrap();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("пример"),
             String::from("Я"),
-        ])));
+        ]))));
         let augmentor = OcrAugmentor::new(
+            Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
             AugCountParams::new(Some(2), Some(6), None),
             Some(4),
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
+            false,
         );
         let input_string = String::from("Очень важный пример для аугментации");
         let mut doc = Doc::new(&input_string);
@@ -129,4 +159,60 @@ mod tests {
         );
         assert!(result.contains("пример"));
     }
+
+    #[test]
+    fn test_insert() {
+        let mut model = OcrModel::new(String::from("test_res/ocr_en.json"));
+        model.load_model().unwrap();
+        let arc_model = Arc::new(model);
+        let augmentor = OcrAugmentor::new(
+            Action::Insert,
+            AugCountParams::new(Some(1), Some(1), None),
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::clone(&arc_model),
+            Arc::new(None),
+            false,
+        );
+        let input_string = String::from("Boom 1000");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        augmentor.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 2);
+        assert_eq!(
+            utils::get_chars_len(&result),
+            utils::get_chars_len(&input_string) + 2
+        );
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), input_string);
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut model = OcrModel::new(String::from("test_res/ocr_en.json"));
+        model.load_model().unwrap();
+        let arc_model = Arc::new(model);
+        let augmentor = OcrAugmentor::new(
+            Action::Delete,
+            AugCountParams::new(Some(1), Some(1), None),
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::clone(&arc_model),
+            Arc::new(None),
+            false,
+        );
+        let input_string = String::from("Boom why 1000");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        augmentor.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 2);
+        assert_eq!(
+            utils::get_chars_len(&result),
+            utils::get_chars_len(&input_string) - 2
+        );
+        assert!(result.contains(" why "));
+    }
 }
@@ -1,11 +1,10 @@
-use super::super::{Action, AugCountParams, BaseAugmentor};
+use super::super::{Action, AugCountParams, BaseAugmentor, Stopwords};
 use super::CharacterAugmentor;
 use crate::doc::{Doc, TokenType};
 use crate::model::character::RandomCharModel;
-use crate::model::BaseModel;
 use rand::prelude::IteratorRandom;
 use rand::rngs::StdRng;
-use std::collections::HashSet;
+use rand::Rng;
 use std::sync::Arc;
 
 /// Different swap strategies
@@ -31,21 +30,39 @@ pub struct RandomCharAugmentor {
     /// RandomCharModel
     model: Arc<RandomCharModel>,
     /// Filter, Set of words that cannot be augmented
-    stopwords: Arc<Option<HashSet<String>>>,
+    stopwords: Arc<Option<Stopwords>>,
     /// Choosen swap strategy
     swapmode: SwapMode,
+    /// Operate on extended grapheme clusters instead of chars
+    use_graphemes: bool,
 }
 
 impl RandomCharAugmentor {
+    /// Actions, supported by augmentor
+    pub const ACTIONS: [Action; 4] = [
+        Action::Insert,
+        Action::Substitute,
+        Action::Delete,
+        Action::Swap,
+    ];
+
+    /// Panics, if `action` is not one of `ACTIONS`
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         action: Action,
         aug_params_char: AugCountParams,
         aug_params_word: AugCountParams,
         min_chars: Option<usize>,
         model: Arc<RandomCharModel>,
-        stopwords: Arc<Option<HashSet<String>>>,
+        stopwords: Arc<Option<Stopwords>>,
         swapmode: String,
+        use_graphemes: bool,
     ) -> Self {
+        assert!(
+            Self::ACTIONS.contains(&action),
+            "RandomCharAugmentor doesn't support action '{}'",
+            action.name()
+        );
         let swapmode = match &swapmode[..] {
             "adjacent" => SwapMode::Adjacent,
             "middle" => SwapMode::Middle,
@@ -60,87 +77,10 @@ impl RandomCharAugmentor {
             model,
             stopwords,
             swapmode,
+            use_graphemes,
         }
     }
 
-    /// Closure performed for each character in the word
-    /// to make predictions for characters that are sampled
-    /// and insert new char
-    fn insert_predicted_char(
-        &self,
-        idx: usize,
-        ch: char,
-        char_idxs: &HashSet<usize>,
-        rng: &mut StdRng,
-    ) -> String {
-        let ch_str = ch.to_string();
-        if char_idxs.contains(&idx) {
-            let predict = self.get_model().predict(&ch_str);
-            if let Some(predicted) = predict {
-                let replacer = predicted.into_iter().choose(rng);
-                if let Some(value) = replacer {
-                    let mut value = value.clone();
-                    value.push_str(&ch_str);
-                    return value;
-                }
-            }
-        }
-        ch_str
-    }
-
-    /// Action::Insert augmentation
-    fn insert(&self, doc: &mut Doc, rng: &mut StdRng) {
-        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
-        let mut change_seq = 0;
-        for (_, a_token) in aug_tokens {
-            let original_token = a_token.get_original();
-            let aug_chars_indexes = self.sample_chars_to_aug(original_token, rng);
-            if aug_chars_indexes.len() == 0 {
-                continue;
-            }
-            let mut result = String::with_capacity(original_token.byte_len() * 2);
-            original_token
-                .token()
-                .chars()
-                .enumerate()
-                .map(|(idx, ch)| self.insert_predicted_char(idx, ch, &aug_chars_indexes, rng))
-                .for_each(|x| result.push_str(&x));
-            result.shrink_to_fit();
-            a_token.change(TokenType::WordToken, result);
-            change_seq += 1;
-        }
-        doc.set_change_count(change_seq);
-    }
-
-    /// Action::Delete augmentation
-    fn delete(&self, doc: &mut Doc, rng: &mut StdRng) {
-        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
-        let mut change_seq = 0;
-        for (_, a_token) in aug_tokens {
-            let original_token = a_token.get_original();
-            let aug_chars_indexes = self.sample_chars_to_aug(original_token, rng);
-            if aug_chars_indexes.len() == 0 {
-                continue;
-            }
-            let mut result = String::with_capacity(original_token.byte_len());
-            original_token
-                .token()
-                .chars()
-                .enumerate()
-                .map(|(idx, ch)| {
-                    if aug_chars_indexes.contains(&idx) {
-                        return String::new();
-                    }
-                    return ch.to_string();
-                })
-                .for_each(|x| result.push_str(&x));
-            result.shrink_to_fit();
-            a_token.change(TokenType::WordToken, result);
-            change_seq += 1;
-        }
-        doc.set_change_count(change_seq);
-    }
-
     /// Implementation of different swap strategies
     fn get_swap_position(&self, pos: usize, token_length: usize, rng: &mut StdRng) -> usize {
         let new_pos: usize;
@@ -151,7 +91,7 @@ impl RandomCharAugmentor {
                 } else if pos == token_length {
                     new_pos = pos - 1;
                 } else {
-                    if rand::random() {
+                    if rng.gen_bool(0.5) {
                         new_pos = pos + 1
                     } else {
                         new_pos = pos - 1
@@ -188,86 +128,56 @@ impl RandomCharAugmentor {
         }
     }
 
+    /// Swap two chars (or grapheme clusters) with saving uppercase/lowercase on their positions
+    ///
+    /// Returns new values for (origin, swap) positions
+    fn swap_with_case(origin: &str, swap: &str) -> (String, String) {
+        let is_alphabetic = |x: &str| x.chars().next().is_some_and(char::is_alphabetic);
+        if !(is_alphabetic(origin) && is_alphabetic(swap)) {
+            return (swap.to_string(), origin.to_string());
+        }
+        let origin_upper = origin.to_uppercase() == origin;
+        let swap_upper = swap.to_uppercase() == swap;
+        match (origin_upper, swap_upper) {
+            (true, false) => (swap.to_uppercase(), origin.to_lowercase()),
+            (false, true) => (swap.to_lowercase(), origin.to_uppercase()),
+            (_, _) => (swap.to_string(), origin.to_string()),
+        }
+    }
+
     /// Action::Swap augmentation with saving uppercase/lowercase
     fn swap(&self, doc: &mut Doc, rng: &mut StdRng) {
         let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
         let mut change_seq = 0;
         for (_, a_token) in aug_tokens {
-            let original_token = a_token.get_original();
-            if a_token.get_original().utf8_len() < 2 {
+            let original_token = a_token.get_current();
+            let mut chars: Vec<String> = original_token
+                .split_chars(self.use_graphemes)
+                .into_iter()
+                .map(String::from)
+                .collect();
+            if chars.len() < 2 {
                 continue;
             }
-            let aug_chars_indexes = self.sample_chars_to_aug(original_token, rng);
-            if aug_chars_indexes.len() == 0 {
+            // Swaps depend on each other, so go through sampled chars in order, not in HashSet one
+            let mut aug_chars_indexes: Vec<usize> = self
+                .sample_chars_to_aug(original_token, rng)
+                .into_iter()
+                .collect();
+            if aug_chars_indexes.is_empty() {
                 continue;
             }
-            let mut result = original_token.token().clone();
+            aug_chars_indexes.sort_unstable();
             for aug_char_idx in aug_chars_indexes {
-                let swap_position =
-                    self.get_swap_position(aug_char_idx, original_token.utf8_len() - 1, rng);
-                if swap_position != aug_char_idx {
-                    let mut origin_char_op: Option<char> = None;
-                    let mut swap_char_op: Option<char> = None;
-                    for (i, ch) in result.chars().enumerate() {
-                        if i == aug_char_idx {
-                            origin_char_op = Some(ch)
-                        }
-                        if i == swap_position {
-                            swap_char_op = Some(ch)
-                        }
-                    }
-                    if origin_char_op.is_some() & swap_char_op.is_some() {
-                        let origin_is_alphabetic = origin_char_op.unwrap().is_alphabetic();
-                        let swap_is_alphabetic = swap_char_op.unwrap().is_alphabetic();
-                        let origin_char: String;
-                        let swap_char: String;
-                        if origin_is_alphabetic & swap_is_alphabetic {
-                            let origin_upper = origin_char_op.unwrap().to_uppercase().to_string()
-                                == origin_char_op.unwrap().to_string();
-                            let swap_upper = swap_char_op.unwrap().to_uppercase().to_string()
-                                == swap_char_op.unwrap().to_string();
-                            (origin_char, swap_char) = match (origin_upper, swap_upper) {
-                                (true, false) => {
-                                    let origin_char_val =
-                                        origin_char_op.unwrap().to_lowercase().to_string();
-                                    let swap_char_val =
-                                        swap_char_op.unwrap().to_uppercase().to_string();
-                                    (origin_char_val, swap_char_val)
-                                }
-                                (false, true) => {
-                                    let origin_char_val =
-                                        origin_char_op.unwrap().to_uppercase().to_string();
-                                    let swap_char_val =
-                                        swap_char_op.unwrap().to_lowercase().to_string();
-                                    (origin_char_val, swap_char_val)
-                                }
-                                (_, _) => (
-                                    origin_char_op.unwrap().to_string(),
-                                    swap_char_op.unwrap().to_string(),
-                                ),
-                            };
-                        } else {
-                            origin_char = origin_char_op.unwrap().to_string();
-                            swap_char = swap_char_op.unwrap().to_string();
-                        }
-                        result = result
-                            .chars()
-                            .enumerate()
-                            .map(|(i, ch)| {
-                                if i == aug_char_idx {
-                                    return swap_char.clone();
-                                } else if i == swap_position {
-                                    return origin_char.clone();
-                                } else {
-                                    return ch.to_string();
-                                }
-                            })
-                            .collect();
-                    }
+                let swap_position = self.get_swap_position(aug_char_idx, chars.len() - 1, rng);
+                if swap_position != aug_char_idx && swap_position < chars.len() {
+                    let (origin_value, swap_value) =
+                        Self::swap_with_case(&chars[aug_char_idx], &chars[swap_position]);
+                    chars[aug_char_idx] = origin_value;
+                    chars[swap_position] = swap_value;
                 }
             }
-            result.shrink_to_fit();
-            a_token.change(TokenType::WordToken, result);
+            a_token.change(TokenType::WordToken, chars.concat());
             change_seq += 1;
         }
         doc.set_change_count(change_seq);
@@ -281,6 +191,7 @@ impl BaseAugmentor<RandomCharModel> for RandomCharAugmentor {
             Action::Substitute => self.substitute(doc, rng),
             Action::Delete => self.delete(doc, rng),
             Action::Swap => self.swap(doc, rng),
+            Action::Split => unreachable!("rejected on creation"),
         }
     }
     fn get_action(&self) -> Action {
@@ -295,7 +206,7 @@ impl BaseAugmentor<RandomCharModel> for RandomCharAugmentor {
     fn get_model(&self) -> &RandomCharModel {
         self.model.as_ref()
     }
-    fn get_stopwords(&self) -> Option<&HashSet<String>> {
+    fn get_stopwords(&self) -> Option<&Stopwords> {
         self.stopwords.as_ref().as_ref()
     }
 }
@@ -304,25 +215,30 @@ impl CharacterAugmentor<RandomCharModel> for RandomCharAugmentor {
     fn get_aug_params_char(&self) -> &AugCountParams {
         &self.aug_params_char
     }
+
+    fn get_use_graphemes(&self) -> bool {
+        self.use_graphemes
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::doc::Doc;
+    use crate::doc::{Doc, UnicodeWordTokenizer};
     use crate::utils;
     use rand::rngs::StdRng;
     use rand::SeedableRng;
+    use std::collections::HashSet;
 
     #[test]
     fn test_substitute_some_data() {
         let mut model = RandomCharModel::new(true, true, true, true, "en", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("The"),
-        ])));
+        ]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
@@ -331,6 +247,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("The quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -352,7 +269,9 @@ mod tests {
         let mut model = RandomCharModel::new(true, true, true, true, "ru", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([String::from("Привет")])));
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+            "Привет",
+        )]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Substitute,
             AugCountParams::new(Some(1), Some(5), None),
@@ -361,6 +280,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("Привет, попробуем аугментировать эту строку");
         let mut doc = Doc::new(&input_string);
@@ -381,10 +301,10 @@ mod tests {
         let mut model = RandomCharModel::new(true, true, true, true, "en", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("The"),
-        ])));
+        ]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Insert,
             AugCountParams::new(Some(1), Some(5), None),
@@ -393,6 +313,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("The quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -411,7 +332,9 @@ mod tests {
         let mut model = RandomCharModel::new(true, true, true, true, "ru", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([String::from("Привет")])));
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+            "Привет",
+        )]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Insert,
             AugCountParams::new(Some(1), Some(5), None),
@@ -420,6 +343,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("Привет, попробуем аугментировать эту строку");
         let mut doc = Doc::new(&input_string);
@@ -442,6 +366,7 @@ mod tests {
             Arc::new(RandomCharModel::new(false, false, false, false, "en", None)),
             Arc::new(None),
             String::from("adjacent"),
+            false,
         );
         let mut rng: StdRng = SeedableRng::from_entropy();
         let swap_position = aug.get_swap_position(0, 5, &mut rng);
@@ -462,6 +387,7 @@ mod tests {
             Arc::new(RandomCharModel::new(false, false, false, false, "en", None)),
             Arc::new(None),
             String::from("middle"),
+            false,
         );
         let mut rng: StdRng = SeedableRng::from_entropy();
         for _ in 0..20 {
@@ -490,6 +416,7 @@ mod tests {
             Arc::new(RandomCharModel::new(false, false, false, false, "en", None)),
             Arc::new(None),
             String::from("random"),
+            false,
         );
         let mut rng: StdRng = SeedableRng::from_entropy();
         for _ in 0..20 {
@@ -507,10 +434,10 @@ mod tests {
         let mut model = RandomCharModel::new(true, true, true, true, "ru", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("The"),
-        ])));
+        ]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Swap,
             AugCountParams::new(Some(1), Some(5), None),
@@ -519,6 +446,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("The quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -540,7 +468,9 @@ mod tests {
         let mut model = RandomCharModel::new(true, true, true, true, "ru", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([String::from("Привет")])));
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+            "Привет",
+        )]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Swap,
             AugCountParams::new(Some(1), Some(5), None),
@@ -549,6 +479,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("Привет, попробуем аугментировать эту строку");
         let mut doc = Doc::new(&input_string);
@@ -564,15 +495,57 @@ mod tests {
         assert!(result.contains("Привет"));
     }
 
+    #[test]
+    fn test_swap_graphemes() {
+        let mut model = RandomCharModel::new(true, true, true, true, "en", None);
+        model.load_model();
+        let augmentor = RandomCharAugmentor::new(
+            Action::Swap,
+            AugCountParams::new(Some(1), Some(10), Some(1.0)),
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(model),
+            Arc::new(None),
+            String::from("random"),
+            true,
+        );
+        let input_string = "cafe\u{301}s";
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        for _ in 0..20 {
+            let mut doc = Doc::new_with_tokenizer(input_string, &UnicodeWordTokenizer);
+            augmentor.augment(&mut doc, &mut rng);
+            let result = doc.get_augmented_string();
+            // Combining accent always stays with its base char
+            assert!(result.contains("e\u{301}"));
+            let mut result_chars = utils::split_chars(&result, true);
+            let mut input_chars = utils::split_chars(input_string, true);
+            result_chars.sort();
+            input_chars.sort();
+            assert_eq!(result_chars, input_chars);
+        }
+    }
+
+    #[test]
+    fn test_swap_with_case() {
+        assert_eq!(
+            RandomCharAugmentor::swap_with_case("T", "h"),
+            (String::from("H"), String::from("t"))
+        );
+        assert_eq!(
+            RandomCharAugmentor::swap_with_case("a", "1"),
+            (String::from("1"), String::from("a"))
+        );
+    }
+
     #[test]
     fn test_delete_some_data() {
         let mut model = RandomCharModel::new(true, true, true, true, "en", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([
             String::from("fox"),
             String::from("The"),
-        ])));
+        ]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Delete,
             AugCountParams::new(Some(1), Some(5), None),
@@ -581,6 +554,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("The quick brown fox jumps over the lazy dog .");
         let mut doc = Doc::new(&input_string);
@@ -599,7 +573,9 @@ mod tests {
         let mut model = RandomCharModel::new(true, true, true, true, "ru", None);
         model.load_model();
         let arc_model = Arc::new(model);
-        let stopwords = Arc::new(Some(HashSet::from([String::from("Привет")])));
+        let stopwords = Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+            "Привет",
+        )]))));
         let augmentor = RandomCharAugmentor::new(
             Action::Delete,
             AugCountParams::new(Some(1), Some(5), None),
@@ -608,6 +584,7 @@ mod tests {
             Arc::clone(&arc_model),
             Arc::clone(&stopwords),
             String::new(),
+            false,
         );
         let input_string = String::from("Привет, попробуем аугментировать эту строку");
         let mut doc = Doc::new(&input_string);
@@ -619,4 +596,19 @@ mod tests {
         assert!(utils::get_chars_len(&result) < utils::get_chars_len(&input_string));
         assert!(result.contains("Привет"));
     }
+
+    #[test]
+    #[should_panic(expected = "doesn't support action 'split'")]
+    fn test_unsupported_action() {
+        RandomCharAugmentor::new(
+            Action::Split,
+            AugCountParams::new(None, None, None),
+            AugCountParams::new(None, None, None),
+            None,
+            Arc::new(RandomCharModel::from_candidates(vec![String::from("a")])),
+            Arc::new(None),
+            String::new(),
+            false,
+        );
+    }
 }
@@ -0,0 +1,364 @@
+//! Property tests: every random decision of augmentors goes through the passed RNG,
+//! so for a fixed seed the output is byte-identical across runs.
+//!
+//! Augmentors and their models are built from scratch for each run,
+//! so results, that depend on HashMap / HashSet iteration order, are caught too.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use proptest::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::character::{
+    ConfusionAugmentor, HomoglyphAugmentor, KeyboardAugmentor, OcrAugmentor, RandomCharAugmentor,
+};
+use super::word::{
+    AntonymAugmentor, LayoutSwitchAugmentor, RandomWordAugmentor, ReservedAugmentor,
+    SpellingAugmentor, SplitAugmentor, SwitchMode, SynonymAugmentor, TfIdfAugmentor,
+    WordEmbsAugmentor,
+};
+use super::{Action, AugCountParams, BaseAugmentor};
+use crate::doc::{AlphanumericTokenizer, Doc};
+use crate::model::character::{
+    ConfusionModel, HomoglyphModel, KeyboardLayout, KeyboardModel, LayoutSwitchModel, OcrModel,
+    RandomCharModel,
+};
+use crate::model::word::{
+    AntonymModel, RandomWordModel, ReservedModel, SpellingModel, SynonymModel, TfIdfModel,
+    WordEmbsModel,
+};
+
+type AugmentFn = Box<dyn Fn(&mut Doc, &mut StdRng)>;
+
+const VOCAB: [&str; 24] = [
+    "good",
+    "hot",
+    "because",
+    "receive",
+    "the",
+    "car",
+    "big",
+    "travel",
+    "king",
+    "woman",
+    "apple",
+    "New",
+    "York",
+    "NYC",
+    "Fwd",
+    "cat",
+    "dog",
+    "привет",
+    "мир",
+    "ghbdtn",
+    "quick",
+    "fox",
+    "42",
+    "!",
+];
+
+fn params(aug_p: f32) -> AugCountParams {
+    AugCountParams::new(None, None, Some(aug_p))
+}
+
+fn boxed<A, M>(augmentor: A) -> AugmentFn
+where
+    A: BaseAugmentor<M> + 'static,
+    M: crate::model::BaseModel,
+{
+    Box::new(move |doc, rng| augmentor.augment(doc, rng))
+}
+
+fn keyboard_augmentor(action: Action) -> AugmentFn {
+    let mut model = KeyboardModel::new(true, true, true, String::from("test_res/keyboard_en.json"));
+    model.load_model().unwrap();
+    let model = Arc::new(model);
+    boxed(KeyboardAugmentor::new(
+        action,
+        params(0.5),
+        params(0.5),
+        None,
+        model,
+        Arc::new(None),
+        false,
+    ))
+}
+
+fn ocr_augmentor(action: Action) -> AugmentFn {
+    let mut model = OcrModel::new(String::from("test_res/ocr_en.json"));
+    model.load_model().unwrap();
+    let model = Arc::new(model);
+    boxed(OcrAugmentor::new(
+        action,
+        params(0.5),
+        params(0.5),
+        None,
+        model,
+        Arc::new(None),
+        false,
+    ))
+}
+
+fn random_char_augmentor(action: Action, swapmode: &str) -> AugmentFn {
+    let mut model = RandomCharModel::new(true, true, true, true, "en", None);
+    model.load_model();
+    boxed(RandomCharAugmentor::new(
+        action,
+        params(0.5),
+        params(0.5),
+        None,
+        Arc::new(model),
+        Arc::new(None),
+        String::from(swapmode),
+        false,
+    ))
+}
+
+fn random_word_augmentor(action: Action) -> AugmentFn {
+    let model = match action {
+        Action::Substitute => RandomWordModel::from_map(HashMap::from([
+            (
+                String::from("good"),
+                vec![String::from("fine"), String::from("nice")],
+            ),
+            (
+                String::from("dog"),
+                vec![String::from("puppy"), String::from("hound")],
+            ),
+        ])),
+        _ => RandomWordModel::from_vec(vec![String::from("one"), String::from("two")]),
+    };
+    boxed(RandomWordAugmentor::new(
+        action,
+        params(0.5),
+        Arc::new(model),
+        Arc::new(None),
+    ))
+}
+
+fn tfidf_augmentor(action: Action) -> AugmentFn {
+    let corpus = [
+        String::from("The cat sat on the mat"),
+        String::from("The dog ate the bone"),
+        String::from("The good king"),
+    ];
+    let model = TfIdfModel::train(&corpus, 3, &AlphanumericTokenizer);
+    boxed(TfIdfAugmentor::new(
+        action,
+        params(0.5),
+        None,
+        Arc::new(model),
+        Arc::new(None),
+    ))
+}
+
+fn layout_switch_augmentor(mode: SwitchMode) -> AugmentFn {
+    let model = LayoutSwitchModel::from_layouts(
+        &KeyboardLayout::from_name("qwerty").unwrap(),
+        &KeyboardLayout::from_name("jcuken").unwrap(),
+        true,
+    );
+    boxed(LayoutSwitchAugmentor::new(
+        params(0.5),
+        None,
+        Arc::new(model),
+        Arc::new(None),
+        mode,
+    ))
+}
+
+/// Build every augmentor with every action and swap mode from scratch
+fn build_augmentors() -> Vec<(&'static str, AugmentFn)> {
+    let mut homoglyph_model = HomoglyphModel::new(String::from("test_res/homoglyph_en.json"));
+    homoglyph_model.load_model().unwrap();
+    let confusion_model = ConfusionModel::train(
+        &[
+            (String::from("good"), String::from("g00d")),
+            (String::from("the"), String::from("tne")),
+        ],
+        1,
+    );
+    let mut antonym_model = AntonymModel::new(String::from("test_res/antonyms.json"));
+    antonym_model.load_model().unwrap();
+    let mut spelling_model = SpellingModel::new(String::from("test_res/spelling_en.json"), true);
+    spelling_model.load_model().unwrap();
+    let mut synonym_model = SynonymModel::new(String::from("test_res/synonyms.json"), None);
+    synonym_model.load_model().unwrap();
+    let mut word_embs_model =
+        WordEmbsModel::new(String::from("test_res/embs_glove.txt"), None, None, 2);
+    word_embs_model.load_model().unwrap();
+    let reserved_model = ReservedModel::new(
+        vec![
+            vec![
+                String::from("New York"),
+                String::from("NYC"),
+                String::from("Big Apple"),
+            ],
+            vec![String::from("FW"), String::from("Fwd")],
+        ],
+        false,
+    );
+
+    vec![
+        ("keyboard insert", keyboard_augmentor(Action::Insert)),
+        (
+            "keyboard substitute",
+            keyboard_augmentor(Action::Substitute),
+        ),
+        ("keyboard delete", keyboard_augmentor(Action::Delete)),
+        ("ocr insert", ocr_augmentor(Action::Insert)),
+        ("ocr substitute", ocr_augmentor(Action::Substitute)),
+        ("ocr delete", ocr_augmentor(Action::Delete)),
+        (
+            "homoglyph",
+            boxed(HomoglyphAugmentor::new(
+                params(0.5),
+                params(0.5),
+                None,
+                Arc::new(homoglyph_model),
+                Arc::new(None),
+                false,
+            )),
+        ),
+        (
+            "confusion",
+            boxed(ConfusionAugmentor::new(
+                params(0.5),
+                params(0.5),
+                None,
+                Arc::new(confusion_model),
+                Arc::new(None),
+                false,
+            )),
+        ),
+        (
+            "random char insert",
+            random_char_augmentor(Action::Insert, "adjacent"),
+        ),
+        (
+            "random char substitute",
+            random_char_augmentor(Action::Substitute, "adjacent"),
+        ),
+        (
+            "random char delete",
+            random_char_augmentor(Action::Delete, "adjacent"),
+        ),
+        (
+            "random char swap adjacent",
+            random_char_augmentor(Action::Swap, "adjacent"),
+        ),
+        (
+            "random char swap middle",
+            random_char_augmentor(Action::Swap, "middle"),
+        ),
+        (
+            "random char swap random",
+            random_char_augmentor(Action::Swap, "random"),
+        ),
+        ("random word insert", random_word_augmentor(Action::Insert)),
+        (
+            "random word substitute",
+            random_word_augmentor(Action::Substitute),
+        ),
+        ("random word swap", random_word_augmentor(Action::Swap)),
+        ("random word delete", random_word_augmentor(Action::Delete)),
+        (
+            "antonym",
+            boxed(AntonymAugmentor::new(
+                params(0.5),
+                None,
+                Arc::new(antonym_model),
+                Arc::new(None),
+            )),
+        ),
+        (
+            "spelling",
+            boxed(SpellingAugmentor::new(
+                params(0.5),
+                None,
+                Arc::new(spelling_model),
+                Arc::new(None),
+            )),
+        ),
+        (
+            "split",
+            boxed(SplitAugmentor::new(params(0.5), Some(2), Arc::new(None))),
+        ),
+        (
+            "synonym",
+            boxed(SynonymAugmentor::new(
+                params(0.5),
+                None,
+                Arc::new(synonym_model),
+                Arc::new(None),
+            )),
+        ),
+        (
+            "word embs",
+            boxed(WordEmbsAugmentor::new(
+                params(0.5),
+                None,
+                Arc::new(word_embs_model),
+                Arc::new(None),
+            )),
+        ),
+        ("tfidf insert", tfidf_augmentor(Action::Insert)),
+        ("tfidf substitute", tfidf_augmentor(Action::Substitute)),
+        (
+            "reserved",
+            boxed(ReservedAugmentor::new(
+                params(0.5),
+                Arc::new(reserved_model),
+                Arc::new(None),
+            )),
+        ),
+        (
+            "layout switch word",
+            layout_switch_augmentor(SwitchMode::Word),
+        ),
+        (
+            "layout switch suffix",
+            layout_switch_augmentor(SwitchMode::Suffix),
+        ),
+    ]
+}
+
+/// Augment text `n` times with every augmentor, RNG of each augmentation is derived from `seed`
+fn run(seed: u64, text: &str, n: usize) -> Vec<(&'static str, Vec<String>)> {
+    build_augmentors()
+        .into_iter()
+        .map(|(name, augment)| {
+            let mut doc = Doc::new(&String::from(text));
+            let outputs = (0..n)
+                .map(|idx| {
+                    let mut rng = StdRng::seed_from_u64(seed.wrapping_add(idx as u64));
+                    augment(&mut doc, &mut rng);
+                    let output = doc.get_augmented_string();
+                    doc.set_to_original();
+                    output
+                })
+                .collect();
+            (name, outputs)
+        })
+        .collect()
+}
+
+fn text_strategy() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::sample::select(VOCAB.to_vec()), 0..12)
+        .prop_map(|words| words.join(" "))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn test_same_seed_same_output(seed in any::<u64>(), text in text_strategy()) {
+        let first = run(seed, &text, 4);
+        let second = run(seed, &text, 4);
+        for ((name, first), (_, second)) in first.iter().zip(second.iter()) {
+            prop_assert_eq!(first, second, "{} is not reproducible", name);
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use std::error::Error;
+use std::sync::Arc;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use super::character::{
+    ConfusionAugmentor, HomoglyphAugmentor, KeyboardAugmentor, OcrAugmentor, RandomCharAugmentor,
+};
+use super::word::{
+    AntonymAugmentor, LayoutSwitchAugmentor, RandomWordAugmentor, ReservedAugmentor,
+    SpellingAugmentor, SplitAugmentor, SynonymAugmentor, TfIdfAugmentor, WordEmbsAugmentor,
+};
+use super::{Action, AugCountParams, BaseAugmentor, Stopwords};
+use crate::doc::Doc;
+use crate::model::character::{
+    ConfusionModel, HomoglyphModel, KeyboardModel, LayoutSwitchModel, OcrModel, RandomCharModel,
+};
+use crate::model::word::{
+    AntonymModel, RandomWordModel, ReservedModel, SpellingModel, SynonymModel, TfIdfModel,
+    WordEmbsModel,
+};
+use crate::model::EmptyModel;
+
+/// Augmentor with erased model type, so augmentors of different kinds can be steps of one pipeline
+pub trait DocAugmentor: Send + Sync {
+    fn augment_doc(&self, doc: &mut Doc, rng: &mut StdRng);
+}
+
+macro_rules! impl_doc_augmentor {
+    ($($augmentor:ty => $model:ty),* $(,)?) => {
+        $(
+            impl DocAugmentor for $augmentor {
+                fn augment_doc(&self, doc: &mut Doc, rng: &mut StdRng) {
+                    BaseAugmentor::<$model>::augment(self, doc, rng)
+                }
+            }
+        )*
+    };
+}
+
+impl_doc_augmentor!(
+    ConfusionAugmentor => ConfusionModel,
+    HomoglyphAugmentor => HomoglyphModel,
+    KeyboardAugmentor => KeyboardModel,
+    OcrAugmentor => OcrModel,
+    RandomCharAugmentor => RandomCharModel,
+    AntonymAugmentor => AntonymModel,
+    LayoutSwitchAugmentor => LayoutSwitchModel,
+    RandomWordAugmentor => RandomWordModel,
+    ReservedAugmentor => ReservedModel,
+    SpellingAugmentor => SpellingModel,
+    SplitAugmentor => EmptyModel,
+    SynonymAugmentor => SynonymModel,
+    TfIdfAugmentor => TfIdfModel,
+    WordEmbsAugmentor => WordEmbsModel,
+    PipelineAugmentor => EmptyModel,
+);
+
+/// How steps of pipeline are applied
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PipelineMode {
+    /// Every step in order, each with its own probability
+    Sequential,
+    /// One step, drawn proportionally to its weight
+    OneOf,
+}
+
+impl PipelineMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sequential" => Some(PipelineMode::Sequential),
+            "one_of" => Some(PipelineMode::OneOf),
+            _ => None,
+        }
+    }
+}
+
+/// Augmentor, which applies other augmentors (or pipelines) to the same Doc
+///
+/// Each step works on top of the previous one, text is tokenized only once
+#[derive(Clone)]
+pub struct PipelineAugmentor {
+    mode: PipelineMode,
+    steps: Vec<Arc<dyn DocAugmentor>>,
+    /// Sequential: probability to apply each step, OneOf: weight of each step
+    weights: Vec<f64>,
+    /// Pipeline doesn't have own parameters, they are defined in steps
+    aug_params_word: AugCountParams,
+    /// Pipeline doesn't need any model
+    model: EmptyModel,
+}
+
+impl PipelineAugmentor {
+    pub fn new(
+        mode: PipelineMode,
+        steps: Vec<Arc<dyn DocAugmentor>>,
+        weights: Vec<f64>,
+    ) -> Result<Self, Box<dyn Error>> {
+        if steps.is_empty() {
+            return Err("pipeline must have at least one step".into());
+        }
+        if steps.len() != weights.len() {
+            return Err(format!("got {} steps and {} weights", steps.len(), weights.len()).into());
+        }
+        match mode {
+            PipelineMode::Sequential => {
+                if weights.iter().any(|p| !(0.0..=1.0).contains(p)) {
+                    return Err("probability of step must be in range [0, 1]".into());
+                }
+            }
+            PipelineMode::OneOf => {
+                WeightedIndex::new(&weights)?;
+            }
+        }
+        Ok(Self {
+            mode,
+            steps,
+            weights,
+            aug_params_word: AugCountParams::new(None, None, None),
+            model: EmptyModel,
+        })
+    }
+
+    /// Apply every step in order
+    pub fn sequential(steps: Vec<Arc<dyn DocAugmentor>>) -> Result<Self, Box<dyn Error>> {
+        let weights = vec![1.0; steps.len()];
+        Self::new(PipelineMode::Sequential, steps, weights)
+    }
+
+    /// Apply every step in order with probability `p`
+    pub fn sometimes(steps: Vec<Arc<dyn DocAugmentor>>, p: f64) -> Result<Self, Box<dyn Error>> {
+        let weights = vec![p; steps.len()];
+        Self::new(PipelineMode::Sequential, steps, weights)
+    }
+
+    /// Apply one of steps, drawn proportionally to `weights`
+    pub fn one_of(
+        steps: Vec<Arc<dyn DocAugmentor>>,
+        weights: Vec<f64>,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::new(PipelineMode::OneOf, steps, weights)
+    }
+
+    /// Apply step and commit its changes, so the next step augments the result.
+    /// Returns number of changes made by step
+    fn apply_step(step: &dyn DocAugmentor, doc: &mut Doc, rng: &mut StdRng) -> usize {
+        doc.set_change_count(0);
+        step.augment_doc(doc, rng);
+        doc.commit_changes();
+        doc.get_changed_count()
+    }
+}
+
+impl BaseAugmentor<EmptyModel> for PipelineAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let mut change_seq = 0;
+        match self.mode {
+            PipelineMode::Sequential => {
+                for (step, p) in self.steps.iter().zip(self.weights.iter()) {
+                    if rng.gen_bool(*p) {
+                        change_seq += Self::apply_step(step.as_ref(), doc, rng);
+                    }
+                }
+            }
+            PipelineMode::OneOf => {
+                // Weights are validated on creation
+                let chosen = WeightedIndex::new(&self.weights).unwrap().sample(rng);
+                change_seq += Self::apply_step(self.steps[chosen].as_ref(), doc, rng);
+            }
+        }
+        doc.set_change_count(change_seq);
+    }
+
+    /// Steps have their own actions
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_model(&self) -> &EmptyModel {
+        &self.model
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::word::RandomWordModel;
+    use rand::SeedableRng;
+    use std::collections::HashMap;
+
+    fn substitute_step(from: &str, to: &str) -> Arc<dyn DocAugmentor> {
+        let model = RandomWordModel::from_map(HashMap::from([(
+            String::from(from),
+            vec![String::from(to)],
+        )]));
+        Arc::new(RandomWordAugmentor::new(
+            Action::Substitute,
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(model),
+            Arc::new(None),
+        ))
+    }
+
+    #[test]
+    fn test_sequential_steps_are_chained() {
+        let pipeline = PipelineAugmentor::sequential(vec![
+            substitute_step("cat", "dog"),
+            substitute_step("dog", "wolf"),
+        ])
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut doc = Doc::new(&String::from("My cat!"));
+        pipeline.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), "My wolf!");
+        assert_eq!(doc.get_changed_count(), 2);
+        assert_eq!(doc.get_alignment()[2], ((3, 6), (3, 7)));
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), "My cat!");
+    }
+
+    #[test]
+    fn test_sometimes_zero_probability() {
+        let pipeline =
+            PipelineAugmentor::sometimes(vec![substitute_step("cat", "dog")], 0.0).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut doc = Doc::new(&String::from("My cat!"));
+        pipeline.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), "My cat!");
+        assert_eq!(doc.get_changed_count(), 0);
+    }
+
+    #[test]
+    fn test_one_of() {
+        let pipeline = PipelineAugmentor::one_of(
+            vec![substitute_step("cat", "dog"), substitute_step("cat", "cow")],
+            vec![0.0, 1.0],
+        )
+        .unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..10 {
+            let mut doc = Doc::new(&String::from("My cat!"));
+            pipeline.augment(&mut doc, &mut rng);
+            assert_eq!(doc.get_augmented_string(), "My cow!");
+        }
+    }
+
+    #[test]
+    fn test_nested_pipeline() {
+        let inner =
+            PipelineAugmentor::one_of(vec![substitute_step("cat", "dog")], vec![1.0]).unwrap();
+        let pipeline =
+            PipelineAugmentor::sequential(vec![Arc::new(inner), substitute_step("My", "Our")])
+                .unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut doc = Doc::new(&String::from("My cat!"));
+        pipeline.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), "Our dog!");
+    }
+
+    #[test]
+    fn test_invalid_pipelines() {
+        let steps = || vec![substitute_step("cat", "dog")];
+        assert!(PipelineAugmentor::new(PipelineMode::Sequential, Vec::new(), Vec::new()).is_err());
+        assert!(PipelineAugmentor::sequential(Vec::new()).is_err());
+        assert!(PipelineAugmentor::new(PipelineMode::Sequential, steps(), vec![]).is_err());
+        assert!(PipelineAugmentor::sometimes(steps(), 1.5).is_err());
+        assert!(PipelineAugmentor::one_of(steps(), vec![0.0]).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use regex::{escape, RegexSet};
+use std::collections::HashSet;
+use std::error::Error;
+
+/// How token is compared with stopwords
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StopwordsMode {
+    /// Exact match: 'The' and 'the' are different words
+    Exact,
+    /// Match after lowercasing: 'The' == 'the'
+    CaseInsensitive,
+    /// Match after Unicode case folding: 'STRASSE' == 'straße'
+    Casefold,
+    /// Every stopword is a regex, that must match the whole token
+    Regex,
+    /// Every stopword is a glob: '*' - any sequence, '?' - any char, '[abc]' - one of chars
+    Glob,
+}
+
+impl StopwordsMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "exact" => Some(StopwordsMode::Exact),
+            "case_insensitive" => Some(StopwordsMode::CaseInsensitive),
+            "casefold" => Some(StopwordsMode::Casefold),
+            "regex" => Some(StopwordsMode::Regex),
+            "glob" => Some(StopwordsMode::Glob),
+            _ => None,
+        }
+    }
+}
+
+/// Set of words (or patterns), that cannot be augmented
+///
+/// Words are normalized and patterns are compiled once on creation
+pub struct Stopwords {
+    mode: StopwordsMode,
+    words: HashSet<String>,
+    patterns: Option<RegexSet>,
+}
+
+impl Stopwords {
+    pub fn new(stopwords: HashSet<String>, mode: StopwordsMode) -> Result<Self, Box<dyn Error>> {
+        let (words, patterns) = match mode {
+            StopwordsMode::Exact => (stopwords, None),
+            StopwordsMode::CaseInsensitive | StopwordsMode::Casefold => {
+                let words = stopwords.iter().map(|w| normalize(w, mode)).collect();
+                (words, None)
+            }
+            StopwordsMode::Regex | StopwordsMode::Glob => {
+                let anchored = stopwords.iter().map(|pattern| match mode {
+                    StopwordsMode::Glob => format!("^(?:{})$", glob_to_regex(pattern)),
+                    _ => format!("^(?:{})$", pattern),
+                });
+                (HashSet::new(), Some(RegexSet::new(anchored)?))
+            }
+        };
+        Ok(Self {
+            mode,
+            words,
+            patterns,
+        })
+    }
+
+    pub fn contains(&self, token: &str) -> bool {
+        match &self.patterns {
+            Some(patterns) => patterns.is_match(token),
+            None => match self.mode {
+                StopwordsMode::Exact => self.words.contains(token),
+                _ => self.words.contains(&normalize(token, self.mode)),
+            },
+        }
+    }
+}
+
+impl From<HashSet<String>> for Stopwords {
+    /// Stopwords with exact matching
+    fn from(stopwords: HashSet<String>) -> Self {
+        Self {
+            mode: StopwordsMode::Exact,
+            words: stopwords,
+            patterns: None,
+        }
+    }
+}
+
+fn normalize(word: &str, mode: StopwordsMode) -> String {
+    match mode {
+        StopwordsMode::Casefold => caseless::default_case_fold_str(word),
+        _ => word.to_lowercase(),
+    }
+}
+
+/// Translate glob to regex: '*' -> '.*', '?' -> '.', '[a-z]' and '[!a-z]' are char classes,
+/// other chars are escaped
+fn glob_to_regex(glob: &str) -> String {
+    let mut pattern = String::with_capacity(glob.len() * 2);
+    let mut in_class = false;
+    for ch in glob.chars() {
+        match ch {
+            '[' if !in_class => {
+                in_class = true;
+                pattern.push(ch);
+            }
+            ']' if in_class => {
+                in_class = false;
+                pattern.push(ch);
+            }
+            '!' if pattern.ends_with('[') && in_class => pattern.push('^'),
+            '\\' | '[' | '&' | '~' if in_class => {
+                pattern.push('\\');
+                pattern.push(ch);
+            }
+            _ if in_class => pattern.push(ch),
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            _ => pattern.push_str(&escape(&ch.to_string())),
+        }
+    }
+    pattern
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_stopwords(words: &[&str], mode: StopwordsMode) -> Stopwords {
+        let words = words.iter().map(|w| String::from(*w)).collect();
+        Stopwords::new(words, mode).unwrap()
+    }
+
+    #[test]
+    fn test_exact() {
+        let stopwords = create_stopwords(&["the"], StopwordsMode::Exact);
+        assert!(stopwords.contains("the"));
+        assert!(!stopwords.contains("The"));
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let stopwords = create_stopwords(&["The", "мир"], StopwordsMode::CaseInsensitive);
+        assert!(stopwords.contains("the"));
+        assert!(stopwords.contains("THE"));
+        assert!(stopwords.contains("Мир"));
+        assert!(!stopwords.contains("then"));
+    }
+
+    #[test]
+    fn test_casefold() {
+        let stopwords = create_stopwords(&["straße", "ΣΟΦΟΣ"], StopwordsMode::Casefold);
+        assert!(stopwords.contains("STRASSE"));
+        assert!(stopwords.contains("Strasse"));
+        assert!(stopwords.contains("σοφος"));
+        assert!(stopwords.contains("σοφοσ"));
+        let stopwords = create_stopwords(&["ﬓ", "ŉ"], StopwordsMode::Casefold);
+        assert!(stopwords.contains("ՄՆ"));
+        assert!(stopwords.contains("ʼN"));
+    }
+
+    #[test]
+    fn test_regex() {
+        let stopwords = create_stopwords(&[r"\d+", "[Tt]he"], StopwordsMode::Regex);
+        assert!(stopwords.contains("2023"));
+        assert!(stopwords.contains("The"));
+        assert!(!stopwords.contains("Them"));
+        assert!(!stopwords.contains("a1"));
+    }
+
+    #[test]
+    fn test_glob() {
+        let stopwords = create_stopwords(
+            &["un*", "?at", "[Tt]he", "a.b", "[!a-z]x"],
+            StopwordsMode::Glob,
+        );
+        assert!(stopwords.contains("unknown"));
+        assert!(stopwords.contains("cat"));
+        assert!(stopwords.contains("The"));
+        assert!(stopwords.contains("a.b"));
+        assert!(!stopwords.contains("acb"));
+        assert!(!stopwords.contains("chat"));
+        assert!(stopwords.contains("Ax"));
+        assert!(!stopwords.contains("ax"));
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        let words = HashSet::from([String::from("(")]);
+        assert!(Stopwords::new(words, StopwordsMode::Regex).is_err());
+    }
+}
@@ -1,4 +1,21 @@
+mod antonym;
 mod base;
+mod layout_switch;
 mod random;
+mod reserved;
+mod spelling;
+mod split;
+mod synonym;
+mod tfidf;
+mod word_embs;
 
+pub use antonym::AntonymAugmentor;
+pub use base::WordAugmentor;
+pub use layout_switch::{LayoutSwitchAugmentor, SwitchMode};
 pub use random::RandomWordAugmentor;
+pub use reserved::ReservedAugmentor;
+pub use spelling::SpellingAugmentor;
+pub use split::SplitAugmentor;
+pub use synonym::SynonymAugmentor;
+pub use tfidf::TfIdfAugmentor;
+pub use word_embs::WordEmbsAugmentor;
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use super::WordAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::Doc;
+use crate::model::word::AntonymModel;
+use rand::rngs::StdRng;
+
+/// Augmentor, which performs AntonymModel on text
+pub struct AntonymAugmentor {
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// AntonymModel
+    model: Arc<AntonymModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl AntonymAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<AntonymModel>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+        }
+    }
+}
+
+impl BaseAugmentor<AntonymModel> for AntonymAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.substitute(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_flag_use_model_in_sampling_words(&self) -> bool {
+        true
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &AntonymModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+impl WordAugmentor<AntonymModel> for AntonymAugmentor {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use rand::SeedableRng;
+
+    fn create_model() -> AntonymModel {
+        AntonymModel::new_from_mapping(HashMap::from([
+            (String::from("good"), vec![String::from("bad")]),
+            (String::from("love"), vec![String::from("hate")]),
+        ]))
+    }
+
+    #[test]
+    fn test_substitute_and_count_antonyms() {
+        let aug = AntonymAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("I love this good movie");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("I hate this bad movie")
+        );
+        assert_eq!(doc.get_changed_count(), 2);
+    }
+
+    #[test]
+    fn test_substitute_reversed_pair() {
+        let aug = AntonymAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("I hate this movie");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("I love this movie")
+        );
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_substitute_limited_by_aug_params() {
+        let aug = AntonymAugmentor::new(
+            AugCountParams::new(None, Some(1), Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("I love this good movie");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_ne!(doc.get_augmented_string(), input_string);
+        assert_eq!(doc.get_changed_count(), 1);
+
+        doc.set_to_original();
+        assert_eq!(doc.get_changed_count(), 0);
+    }
+}
@@ -1,8 +1,31 @@
 use super::super::BaseAugmentor;
+use crate::doc::{Doc, TokenType};
 use crate::model::word::WordModel;
+use rand::prelude::IteratorRandom;
+use rand::rngs::StdRng;
 
+/// Base word augmentors functionality
 pub trait WordAugmentor<T>: BaseAugmentor<T>
 where
     T: WordModel,
 {
+    /// Default substitute method to word models
+    ///
+    /// Each sampled word replaced with random model prediction for it
+    fn substitute(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
+        let mut change_seq = 0;
+        for (_, a_token) in aug_tokens {
+            let original_token = a_token.get_current();
+            let predict = self.get_model().predict(original_token.token());
+            if let Some(predicted) = predict {
+                let replacer = predicted.iter().choose(rng);
+                if let Some(value) = replacer {
+                    a_token.change(TokenType::WordToken, value.to_owned());
+                    change_seq += 1
+                }
+            }
+        }
+        doc.set_change_count(change_seq);
+    }
 }
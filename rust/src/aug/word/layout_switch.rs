@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::{Doc, TokenType};
+use crate::model::character::LayoutSwitchModel;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Which part of sampled word is typed in wrong layout
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SwitchMode {
+    /// Whole word: 'привет' -> 'ghbdtn'
+    Word,
+    /// Random suffix of word, layout was switched in the middle of typing: 'привет' -> 'приdtn'
+    Suffix,
+}
+
+impl SwitchMode {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "word" => Some(SwitchMode::Word),
+            "suffix" => Some(SwitchMode::Suffix),
+            _ => None,
+        }
+    }
+}
+
+/// Augmentor, which performs LayoutSwitchModel on text
+pub struct LayoutSwitchAugmentor {
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// LayoutSwitchModel
+    model: Arc<LayoutSwitchModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+    /// Convert whole word or its suffix
+    mode: SwitchMode,
+}
+
+impl LayoutSwitchAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<LayoutSwitchModel>,
+        stopwords: Arc<Option<Stopwords>>,
+        mode: SwitchMode,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+            mode,
+        }
+    }
+
+    /// Action::Substitute augmentation
+    ///
+    /// Each sampled word (or its suffix) is converted to other layout
+    fn substitute(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
+        let mut change_seq = 0;
+        for (_, a_token) in aug_tokens {
+            let original_token = a_token.get_current().token();
+            let switched = match self.mode {
+                SwitchMode::Word => self.model.switch(original_token),
+                SwitchMode::Suffix => {
+                    let chars_count = original_token.chars().count();
+                    let start_char = match chars_count {
+                        0 | 1 => 0,
+                        _ => rng.gen_range(1..chars_count),
+                    };
+                    let (start_byte, _) = original_token.char_indices().nth(start_char).unwrap();
+                    let mut switched = String::from(&original_token[..start_byte]);
+                    switched.push_str(&self.model.switch(&original_token[start_byte..]));
+                    switched
+                }
+            };
+            if switched != *original_token {
+                a_token.change(TokenType::WordToken, switched);
+                change_seq += 1;
+            }
+        }
+        doc.set_change_count(change_seq);
+    }
+}
+
+impl BaseAugmentor<LayoutSwitchModel> for LayoutSwitchAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.substitute(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &LayoutSwitchModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::character::KeyboardLayout;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn create_model() -> LayoutSwitchModel {
+        LayoutSwitchModel::from_layouts(
+            &KeyboardLayout::from_name("qwerty").unwrap(),
+            &KeyboardLayout::from_name("jcuken").unwrap(),
+            true,
+        )
+    }
+
+    #[test]
+    fn test_switch_whole_words() {
+        let aug = LayoutSwitchAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(Some(Stopwords::from(HashSet::from([String::from("мир")])))),
+            SwitchMode::Word,
+        );
+        let mut doc = Doc::new(&String::from("Привет, мир 2023"));
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), String::from("Ghbdtn, мир 2023"));
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_switch_suffix() {
+        let aug = LayoutSwitchAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+            SwitchMode::Suffix,
+        );
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        for _ in 0..10 {
+            let mut doc = Doc::new(&String::from("привет"));
+            aug.augment(&mut doc, &mut rng);
+            let result = doc.get_augmented_string();
+            assert_eq!(doc.get_changed_count(), 1);
+            assert!(result.starts_with('п'));
+            assert!(result.ends_with('n'));
+            let switched_len = result.chars().filter(|c| c.is_ascii()).count();
+            let prefix: String = result.chars().take(6 - switched_len).collect();
+            assert!("привет".starts_with(&prefix));
+            assert!("ghbdtn".ends_with(&result[prefix.len()..]));
+        }
+    }
+}
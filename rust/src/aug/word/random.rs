@@ -1,38 +1,54 @@
-use std::collections::HashSet;
+use std::cmp::Reverse;
 use std::sync::Arc;
 
 use super::super::BaseAugmentor;
-use crate::aug::{Action, AugCountParams};
+use super::WordAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
 use crate::doc::Doc;
 use crate::doc::TokenType;
 use crate::model::word::RandomWordModel;
 use crate::model::BaseModel;
 use rand::rngs::StdRng;
-use rand::seq::{IteratorRandom, SliceRandom};
+use rand::seq::SliceRandom;
 
 /// Augmentor, which performs RandomWordModel on text
 pub struct RandomWordAugmentor {
-    /// Action to augmentation, set of values {'substitute', 'swap', 'delete'}
+    /// Action to augmentation, set of values {'insert', 'substitute', 'swap', 'delete'}
     action: Action,
     /// Parameteres to calculate number of words that will be augmented
     aug_params_word: AugCountParams,
     /// RandomWordModel
     model: Arc<RandomWordModel>,
     /// Filter, Set of words that cannot be augmented
-    stopwords: Arc<Option<HashSet<String>>>,
+    stopwords: Arc<Option<Stopwords>>,
     /// Flag, use model to filter words to augmentation
     use_model_in_sampler_words: bool,
 }
 
 impl RandomWordAugmentor {
+    /// Actions, supported by augmentor
+    pub const ACTIONS: [Action; 4] = [
+        Action::Insert,
+        Action::Substitute,
+        Action::Delete,
+        Action::Swap,
+    ];
+
+    /// Panics, if `action` is not one of `ACTIONS`
     pub fn new(
         action: Action,
         aug_params_word: AugCountParams,
         model: Arc<RandomWordModel>,
-        stopwords: Arc<Option<HashSet<String>>>,
+        stopwords: Arc<Option<Stopwords>>,
     ) -> Self {
+        assert!(
+            Self::ACTIONS.contains(&action),
+            "RandomWordAugmentor doesn't support action '{}'",
+            action.name()
+        );
         let use_model_in_sampler_words = match action {
             Action::Substitute => true,
+            Action::Insert => true,
             _ => false,
         };
         Self {
@@ -44,21 +60,28 @@ impl RandomWordAugmentor {
         }
     }
 
-    /// Action::Substitute augmentation
-    fn substitute(&self, doc: &mut Doc, rng: &mut StdRng) -> () {
+    /// Action::Insert augmentation
+    ///
+    /// After each sampled word inserts space and random model prediction for this word
+    fn insert(&self, doc: &mut Doc, rng: &mut StdRng) -> () {
         let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
-        let mut change_seq = 0;
-        for (_, a_token) in aug_tokens {
-            let original_token = a_token.get_original();
+        let mut insertions = Vec::with_capacity(aug_tokens.len());
+        for (idx, a_token) in aug_tokens {
+            let original_token = a_token.get_current();
             let predict = self.get_model().predict(&original_token.token());
             if let Some(predicted) = predict {
-                let replacer = predicted.into_iter().choose(rng);
-                if let Some(value) = replacer {
-                    a_token.change(TokenType::WordToken, value.to_owned());
-                    change_seq += 1
+                if let Some(value) = predicted.choose(rng) {
+                    insertions.push((idx, value.to_owned()));
                 }
             }
         }
+        // Insertions shift indexes of next tokens, so we go from the end
+        insertions.sort_by_key(|x| Reverse(x.0));
+        let change_seq = insertions.len();
+        for (idx, value) in insertions {
+            doc.perform_insert_by_idx(idx + 1, TokenType::WordToken, value);
+            doc.perform_insert_by_idx(idx + 1, TokenType::SpaceToken, String::from(" "));
+        }
         doc.set_change_count(change_seq);
     }
 
@@ -101,7 +124,12 @@ impl RandomWordAugmentor {
 
     /// Action::Swap augmentation
     fn swap(&self, doc: &mut Doc, rng: &mut StdRng) -> () {
-        let word_token_indexes = doc.get_word_indexes(self.get_use_special_chars());
+        // Protected tokens can't be moved
+        let word_token_indexes: Vec<usize> = doc
+            .get_word_indexes(self.get_use_special_chars())
+            .into_iter()
+            .filter(|idx| !doc.is_protected(*idx))
+            .collect();
         let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
         let mut change_seq = 0;
         let mut swap_pairs = Vec::with_capacity(aug_tokens.len());
@@ -122,10 +150,11 @@ impl RandomWordAugmentor {
 impl BaseAugmentor<RandomWordModel> for RandomWordAugmentor {
     fn augment(&self, doc: &mut Doc, rng: &mut StdRng) -> () {
         match self.action {
-            Action::Insert => (),
+            Action::Insert => self.insert(doc, rng),
             Action::Substitute => self.substitute(doc, rng),
             Action::Delete => self.delete(doc, rng),
             Action::Swap => self.swap(doc, rng),
+            Action::Split => unreachable!("rejected on creation"),
         }
     }
 
@@ -145,14 +174,17 @@ impl BaseAugmentor<RandomWordModel> for RandomWordAugmentor {
         self.model.as_ref()
     }
 
-    fn get_stopwords(&self) -> Option<&HashSet<String>> {
+    fn get_stopwords(&self) -> Option<&Stopwords> {
         self.stopwords.as_ref().as_ref()
     }
 }
 
+impl WordAugmentor<RandomWordModel> for RandomWordAugmentor {}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
+    use std::collections::HashSet;
 
     use super::*;
     use rand::SeedableRng;
@@ -186,7 +218,7 @@ mod tests {
             Action::Substitute,
             AugCountParams::new(Some(10), Some(100), Some(1.0)),
             Arc::new(model),
-            Arc::new(Some(stopwords)),
+            Arc::new(Some(Stopwords::from(stopwords))),
         );
         let input_string = String::from("My new input string!");
         let mut doc = Doc::new(&input_string);
@@ -244,7 +276,7 @@ mod tests {
             Action::Substitute,
             AugCountParams::new(Some(10), Some(100), Some(1.0)),
             Arc::new(model),
-            Arc::new(Some(stopwords)),
+            Arc::new(Some(Stopwords::from(stopwords))),
         );
         let input_string = String::from("My new input string!");
         let mut doc = Doc::new(&input_string);
@@ -257,6 +289,54 @@ mod tests {
         assert_eq!(input_string, result);
     }
 
+    #[test]
+    fn test_insert_vec_model() {
+        let model = RandomWordModel::from_vec(vec![String::from("word")]);
+        let aug = RandomWordAugmentor::new(
+            Action::Insert,
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(model),
+            Arc::new(None),
+        );
+        let input_string = String::from("My new string!");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("My word new word string word!")
+        );
+        assert_eq!(doc.get_changed_count(), 3);
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), input_string);
+    }
+
+    #[test]
+    fn test_insert_dict_model() {
+        let model = RandomWordModel::from_map(HashMap::from([
+            (
+                String::from("new"),
+                vec![String::from("shiny"), String::from("fresh")],
+            ),
+            (String::from("string"), vec![String::from("value")]),
+        ]));
+        let stopwords = HashSet::from([String::from("string")]);
+        let aug = RandomWordAugmentor::new(
+            Action::Insert,
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(model),
+            Arc::new(Some(Stopwords::from(stopwords))),
+        );
+        let input_string = String::from("My new string!");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert!((result == "My new shiny string!") | (result == "My new fresh string!"));
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
     #[test]
     fn test_swap_tokens() {
         let model = RandomWordModel::from_vec(vec![String::from("word")]);
@@ -323,4 +403,15 @@ mod tests {
         aug.augment(&mut doc, &mut rng);
         assert_eq!(doc.get_augmented_string(), String::from(" !!  !"));
     }
+
+    #[test]
+    #[should_panic(expected = "doesn't support action 'split'")]
+    fn test_unsupported_action() {
+        RandomWordAugmentor::new(
+            Action::Split,
+            AugCountParams::new(None, None, None),
+            Arc::new(RandomWordModel::empty_model()),
+            Arc::new(None),
+        );
+    }
 }
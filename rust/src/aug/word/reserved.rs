@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::Doc;
+use crate::model::word::ReservedModel;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+/// Augmentor, which replaces reserved terms with other variants of them
+pub struct ReservedAugmentor {
+    /// Parameteres to calculate number of terms that will be augmented
+    aug_params_word: AugCountParams,
+    /// ReservedModel
+    model: Arc<ReservedModel>,
+    /// Filter, Set of terms that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl ReservedAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        model: Arc<ReservedModel>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            model,
+            stopwords,
+        }
+    }
+
+    /// Action::Substitute augmentation
+    ///
+    /// Terms can span several tokens, so they are sampled from model matches over all tokens of Doc,
+    /// not from filtered word tokens. Ranges of matches are indexes of Doc tokens
+    fn substitute(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let tokens = doc.get_tokens();
+        let mut candidates = Vec::new();
+        for found in self.model.find_matches(&tokens) {
+            if (found.start..found.end).any(|idx| doc.is_protected(idx)) {
+                continue;
+            }
+            if let Some(stopwords) = self.get_stopwords() {
+                let term: String = tokens[found.start..found.end]
+                    .iter()
+                    .map(|t| t.token().as_str())
+                    .collect();
+                if stopwords.contains(&term) {
+                    continue;
+                }
+            }
+            if let Some(variant) = self.model.get_variants(&found).choose(rng) {
+                candidates.push((found.start, found.end, (*variant).clone()));
+            }
+        }
+
+        let aug_cnt = self.aug_params_word.calculate_aug_cnt(candidates.len());
+        let to_replace: Vec<(usize, usize, String)> =
+            candidates.choose_multiple(rng, aug_cnt).cloned().collect();
+        let change_seq = to_replace.len();
+        for (start, end, variant) in to_replace {
+            doc.perform_replace_range(start, end, variant);
+        }
+        doc.set_change_count(change_seq);
+    }
+}
+
+impl BaseAugmentor<ReservedModel> for ReservedAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.substitute(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_flag_use_model_in_sampling_words(&self) -> bool {
+        true
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        None
+    }
+
+    fn get_model(&self) -> &ReservedModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::TokenType;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn create_model(case_sensitive: bool) -> ReservedModel {
+        ReservedModel::new(
+            vec![
+                vec![String::from("FW"), String::from("Fwd")],
+                vec![String::from("New York"), String::from("NYC")],
+            ],
+            case_sensitive,
+        )
+    }
+
+    #[test]
+    fn test_substitute_multi_word_terms() {
+        let aug = ReservedAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(create_model(true)),
+            Arc::new(None),
+        );
+        let mut doc = Doc::new(&String::from("FW: I love New York, not NYC!"));
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("Fwd: I love NYC, not New York!")
+        );
+        assert_eq!(doc.get_changed_count(), 3);
+
+        doc.set_to_original();
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("FW: I love New York, not NYC!")
+        );
+    }
+
+    #[test]
+    fn test_substitute_case_insensitive() {
+        let input_string = String::from("fw: new york");
+        let mut rng: StdRng = SeedableRng::from_entropy();
+
+        let aug = ReservedAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(create_model(true)),
+            Arc::new(None),
+        );
+        let mut doc = Doc::new(&input_string);
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), input_string);
+        assert_eq!(doc.get_changed_count(), 0);
+
+        let aug = ReservedAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(create_model(false)),
+            Arc::new(None),
+        );
+        let mut doc = Doc::new(&input_string);
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), String::from("Fwd: NYC"));
+        assert_eq!(doc.get_changed_count(), 2);
+    }
+
+    #[test]
+    fn test_substitute_with_stopwords() {
+        let aug = ReservedAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(create_model(true)),
+            Arc::new(Some(Stopwords::from(HashSet::from([String::from(
+                "New York",
+            )])))),
+        );
+        let mut doc = Doc::new(&String::from("FW: New York"));
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), String::from("Fwd: New York"));
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_substitute_limited_by_aug_params() {
+        let aug = ReservedAugmentor::new(
+            AugCountParams::new(None, Some(1), Some(1.0)),
+            Arc::new(create_model(true)),
+            Arc::new(None),
+        );
+        let input_string = String::from("FW: New York");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_ne!(doc.get_augmented_string(), input_string);
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_substitute_after_insertion() {
+        let aug = ReservedAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Arc::new(create_model(true)),
+            Arc::new(None),
+        );
+        let mut doc = Doc::new(&String::from("FW: New York"));
+        doc.perform_insert_by_idx(0, TokenType::WordToken, String::from("Re"));
+        doc.perform_insert_by_idx(1, TokenType::SpaceToken, String::from(" "));
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), String::from("Re Fwd: NYC"));
+        assert_eq!(doc.get_changed_count(), 2);
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use super::WordAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::Doc;
+use crate::model::word::SpellingModel;
+use rand::rngs::StdRng;
+
+/// Augmentor, which performs SpellingModel on text
+pub struct SpellingAugmentor {
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// SpellingModel
+    model: Arc<SpellingModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl SpellingAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<SpellingModel>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+        }
+    }
+}
+
+impl BaseAugmentor<SpellingModel> for SpellingAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.substitute(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_flag_use_model_in_sampling_words(&self) -> bool {
+        true
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &SpellingModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+impl WordAugmentor<SpellingModel> for SpellingAugmentor {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    use super::*;
+    use rand::SeedableRng;
+
+    fn create_model() -> SpellingModel {
+        SpellingModel::new_from_mapping(
+            HashMap::from([
+                (
+                    String::from("because"),
+                    vec![String::from("becuase"), String::from("becasue")],
+                ),
+                (String::from("the"), vec![String::from("teh")]),
+                (String::from("receive"), vec![String::from("recieve")]),
+            ]),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_substitute_all_known_words() {
+        let aug = SpellingAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("I did not receive the letter, because of rain.");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert!(
+            (result == "I did not recieve teh letter, becuase of rain.")
+                | (result == "I did not recieve teh letter, becasue of rain.")
+        );
+        assert_eq!(doc.get_changed_count(), 3);
+    }
+
+    #[test]
+    fn test_substitute_with_stopwords_and_min_chars() {
+        let stopwords = HashSet::from([String::from("because")]);
+        let aug = SpellingAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Some(4),
+            Arc::new(create_model()),
+            Arc::new(Some(Stopwords::from(stopwords))),
+        );
+        let input_string = String::from("I did not receive the letter, because of rain.");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("I did not recieve the letter, because of rain.")
+        );
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_substitute_unknown_words() {
+        let aug = SpellingAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("Nothing to change here");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), input_string);
+        assert_eq!(doc.get_changed_count(), 0);
+    }
+}
@@ -0,0 +1,154 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::Doc;
+use crate::model::EmptyModel;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Augmentor, which splits words into two tokens at a random position
+pub struct SplitAugmentor {
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not split word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// Split doesn't need any model
+    model: EmptyModel,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl SplitAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            min_chars,
+            model: EmptyModel,
+            stopwords,
+        }
+    }
+
+    /// Action::Split augmentation
+    fn split(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
+        let mut splits = Vec::with_capacity(aug_tokens.len());
+        for (idx, a_token) in aug_tokens {
+            let original_token = a_token.get_current();
+            // Word with one char can't be split into two non-empty parts
+            if original_token.utf8_len() < 2 {
+                continue;
+            }
+            let split_char_pos = rng.gen_range(1..original_token.utf8_len());
+            let token_str = original_token.token();
+            let (split_byte_pos, _) = token_str.char_indices().nth(split_char_pos).unwrap();
+            let left = String::from(&token_str[..split_byte_pos]);
+            let right = String::from(&token_str[split_byte_pos..]);
+            splits.push((idx, left, right));
+        }
+        // Insertions shift indexes of next tokens, so we go from the end
+        splits.sort_by_key(|x| Reverse(x.0));
+        let change_seq = splits.len();
+        for (idx, left, right) in splits {
+            doc.perform_split_by_idx(idx, left, right);
+        }
+        doc.set_change_count(change_seq);
+    }
+}
+
+impl BaseAugmentor<EmptyModel> for SplitAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.split(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Split
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &EmptyModel {
+        &self.model
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_split_all_words() {
+        let aug = SplitAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(None),
+        );
+        let input_string = String::from("Split every word!");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert_eq!(result.split(' ').count(), 6);
+        assert_eq!(result.replace(' ', ""), input_string.replace(' ', ""));
+        assert_eq!(doc.get_changed_count(), 3);
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), input_string);
+    }
+
+    #[test]
+    fn test_split_with_min_chars_and_stopwords() {
+        let stopwords = HashSet::from([String::from("Пример")]);
+        let aug = SplitAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Some(4),
+            Arc::new(Some(Stopwords::from(stopwords))),
+        );
+        let input_string = String::from("Пример для разбиения");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        let result = doc.get_augmented_string();
+        assert!(result.starts_with("Пример для "));
+        assert_ne!(result, input_string);
+        assert_eq!(result.split(' ').count(), 4);
+        assert_eq!(
+            utils::get_chars_len(&result),
+            utils::get_chars_len(&input_string) + 1
+        );
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_dont_split_one_char_words() {
+        let aug = SplitAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(None),
+        );
+        let input_string = String::from("a b c");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), input_string);
+        assert_eq!(doc.get_changed_count(), 0);
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use super::WordAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::Doc;
+use crate::model::word::SynonymModel;
+use rand::rngs::StdRng;
+
+/// Augmentor, which performs SynonymModel on text
+pub struct SynonymAugmentor {
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// SynonymModel
+    model: Arc<SynonymModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl SynonymAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<SynonymModel>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+        }
+    }
+}
+
+impl BaseAugmentor<SynonymModel> for SynonymAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.substitute(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_flag_use_model_in_sampling_words(&self) -> bool {
+        true
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &SynonymModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+impl WordAugmentor<SynonymModel> for SynonymAugmentor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::word::PartOfSpeech;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn create_model(pos: Option<PartOfSpeech>) -> SynonymModel {
+        SynonymModel::from_synsets(
+            vec![
+                (
+                    Some(PartOfSpeech::Adjective),
+                    vec![String::from("quick"), String::from("fast")],
+                ),
+                (
+                    Some(PartOfSpeech::Noun),
+                    vec![String::from("fox"), String::from("canine")],
+                ),
+                (
+                    Some(PartOfSpeech::Noun),
+                    vec![String::from("dog"), String::from("hot dog")],
+                ),
+            ],
+            pos,
+        )
+    }
+
+    #[test]
+    fn test_substitute_all_known_words() {
+        let aug = SynonymAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model(None)),
+            Arc::new(None),
+        );
+        let input_string = String::from("The Quick brown fox jumps over the lazy dog");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("The fast brown canine jumps over the lazy hot dog")
+        );
+        assert_eq!(doc.get_changed_count(), 3);
+    }
+
+    #[test]
+    fn test_substitute_restricted_by_pos() {
+        let stopwords = HashSet::from([String::from("dog")]);
+        let aug = SynonymAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model(Some(PartOfSpeech::Noun))),
+            Arc::new(Some(Stopwords::from(stopwords))),
+        );
+        let input_string = String::from("The quick brown fox jumps over the lazy dog");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("The quick brown canine jumps over the lazy dog")
+        );
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+
+    #[test]
+    fn test_substitute_with_min_chars() {
+        let aug = SynonymAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            Some(4),
+            Arc::new(create_model(None)),
+            Arc::new(None),
+        );
+        let input_string = String::from("quick fox");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), String::from("fast fox"));
+    }
+}
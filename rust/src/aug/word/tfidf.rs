@@ -0,0 +1,259 @@
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::{Doc, TokenType};
+use crate::model::word::TfIdfModel;
+use crate::model::BaseModel;
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+
+/// Augmentor, which performs TfIdfModel on text
+pub struct TfIdfAugmentor {
+    /// Action to augmentation, set of values {'insert', 'substitute'}
+    action: Action,
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// TfIdfModel
+    model: Arc<TfIdfModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl TfIdfAugmentor {
+    /// Actions, supported by augmentor
+    pub const ACTIONS: [Action; 2] = [Action::Insert, Action::Substitute];
+
+    /// Panics, if `action` is not one of `ACTIONS`
+    pub fn new(
+        action: Action,
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<TfIdfModel>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        assert!(
+            Self::ACTIONS.contains(&action),
+            "TfIdfAugmentor doesn't support action '{}'",
+            action.name()
+        );
+        Self {
+            action,
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+        }
+    }
+
+    /// Draw one of model candidates, weighted by its TF-IDF score
+    fn choose_candidate(&self, exclude: &str, rng: &mut StdRng) -> Option<String> {
+        let exclude = exclude.to_lowercase();
+        let candidates: Vec<&String> = self
+            .model
+            .predict(&exclude)?
+            .iter()
+            .filter(|x| **x != exclude)
+            .collect();
+        let weighted = candidates.choose_weighted(rng, |x| self.model.get_score(x).unwrap_or(0.0));
+        match weighted {
+            Ok(value) => Some((*value).clone()),
+            // All candidates have zero score, so any of them is fine
+            Err(_) => candidates.choose(rng).map(|x| (*x).clone()),
+        }
+    }
+
+    /// Action::Substitute augmentation
+    ///
+    /// Words with low TF-IDF score have more chances to be replaced
+    fn substitute(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let origin_word_count = doc.get_word_tokens_count(self.get_use_special_chars());
+        let aug_cnt = self.aug_params_word.calculate_aug_cnt(origin_word_count);
+        let filtered_word_tokens = self.get_filtered_word_tokens(doc);
+        if filtered_word_tokens.is_empty() {
+            doc.set_change_count(0);
+            return;
+        }
+
+        let scores: Vec<f32> = filtered_word_tokens
+            .iter()
+            .map(|(_, handler)| {
+                let token = handler.get_current().token();
+                self.model.get_score(token).unwrap_or(0.0)
+            })
+            .collect();
+        let max_score = scores.iter().cloned().fold(0.0, f32::max);
+        let amount = usize::min(aug_cnt, filtered_word_tokens.len());
+        let sampled: HashSet<usize> = index::sample_weighted(
+            rng,
+            filtered_word_tokens.len(),
+            |i| max_score - scores[i] + f32::EPSILON,
+            amount,
+        )
+        .map(|idxs| idxs.into_iter().collect())
+        .unwrap_or_default();
+
+        let mut change_seq = 0;
+        for (pos, (_, a_token)) in filtered_word_tokens.into_iter().enumerate() {
+            if !sampled.contains(&pos) {
+                continue;
+            }
+            let original_token = a_token.get_current().token().clone();
+            if let Some(value) = self.choose_candidate(&original_token, rng) {
+                a_token.change(TokenType::WordToken, value);
+                change_seq += 1;
+            }
+        }
+        doc.set_change_count(change_seq);
+    }
+
+    /// Action::Insert augmentation
+    ///
+    /// After each sampled word inserts space and word, drawn by TF-IDF score
+    fn insert(&self, doc: &mut Doc, rng: &mut StdRng) {
+        let aug_tokens = self.sample_word_tokens_to_aug(doc, rng);
+        let mut insertions = Vec::with_capacity(aug_tokens.len());
+        for (idx, a_token) in aug_tokens {
+            let original_token = a_token.get_current().token();
+            if let Some(value) = self.choose_candidate(original_token, rng) {
+                insertions.push((idx, value));
+            }
+        }
+        // Insertions shift indexes of next tokens, so we go from the end
+        insertions.sort_by_key(|x| Reverse(x.0));
+        let change_seq = insertions.len();
+        for (idx, value) in insertions {
+            doc.perform_insert_by_idx(idx + 1, TokenType::WordToken, value);
+            doc.perform_insert_by_idx(idx + 1, TokenType::SpaceToken, String::from(" "));
+        }
+        doc.set_change_count(change_seq);
+    }
+}
+
+impl BaseAugmentor<TfIdfModel> for TfIdfAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        match self.action {
+            Action::Insert => self.insert(doc, rng),
+            Action::Substitute => self.substitute(doc, rng),
+            _ => unreachable!("rejected on creation"),
+        }
+    }
+
+    fn get_action(&self) -> Action {
+        self.action
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    /// Only words, that model knows, can be substituted
+    fn get_flag_use_model_in_sampling_words(&self) -> bool {
+        matches!(self.action, Action::Substitute)
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &TfIdfModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::AlphanumericTokenizer;
+    use rand::SeedableRng;
+
+    fn create_model() -> TfIdfModel {
+        TfIdfModel::train(
+            &[
+                String::from("The cat sat on the mat"),
+                String::from("The dog ate the bone"),
+                String::from("The bird sang"),
+            ],
+            2,
+            &AlphanumericTokenizer,
+        )
+    }
+
+    #[test]
+    fn test_substitute() {
+        let aug = TfIdfAugmentor::new(
+            Action::Substitute,
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("The cat and the fish");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        let augmented_string = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 3);
+        let words: Vec<&str> = augmented_string.split(' ').collect();
+        for idx in [0, 1, 3] {
+            assert!((words[idx] == "bird") | (words[idx] == "sang"));
+        }
+        assert_eq!(words[2], "and");
+        assert_eq!(words[4], "fish");
+    }
+
+    #[test]
+    fn test_substitute_prefers_low_score_words() {
+        let aug = TfIdfAugmentor::new(
+            Action::Substitute,
+            AugCountParams::new(None, Some(1), Some(0.1)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("the bird");
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        for _ in 0..20 {
+            let mut doc = Doc::new(&input_string);
+            aug.augment(&mut doc, &mut rng);
+            let augmented_string = doc.get_augmented_string();
+            assert!(augmented_string.ends_with(" bird"));
+            assert_eq!(doc.get_changed_count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_insert() {
+        let aug = TfIdfAugmentor::new(
+            Action::Insert,
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("Unknown words");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        let augmented_string = doc.get_augmented_string();
+        assert_eq!(doc.get_changed_count(), 2);
+        let words: Vec<&str> = augmented_string.split(' ').collect();
+        assert_eq!(words.len(), 4);
+        assert_eq!(words[0], "Unknown");
+        assert_eq!(words[2], "words");
+        for idx in [1, 3] {
+            assert!((words[idx] == "bird") | (words[idx] == "sang"));
+        }
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), input_string);
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use super::super::BaseAugmentor;
+use super::WordAugmentor;
+use crate::aug::{Action, AugCountParams, Stopwords};
+use crate::doc::Doc;
+use crate::model::word::WordEmbsModel;
+use rand::rngs::StdRng;
+
+/// Augmentor, which performs WordEmbsModel on text
+pub struct WordEmbsAugmentor {
+    /// Parameteres to calculate number of words that will be augmented
+    aug_params_word: AugCountParams,
+    /// Filter, do not augment word, if it's lenght less than this value
+    min_chars: Option<usize>,
+    /// WordEmbsModel
+    model: Arc<WordEmbsModel>,
+    /// Filter, Set of words that cannot be augmented
+    stopwords: Arc<Option<Stopwords>>,
+}
+
+impl WordEmbsAugmentor {
+    pub fn new(
+        aug_params_word: AugCountParams,
+        min_chars: Option<usize>,
+        model: Arc<WordEmbsModel>,
+        stopwords: Arc<Option<Stopwords>>,
+    ) -> Self {
+        Self {
+            aug_params_word,
+            min_chars,
+            model,
+            stopwords,
+        }
+    }
+}
+
+impl BaseAugmentor<WordEmbsModel> for WordEmbsAugmentor {
+    fn augment(&self, doc: &mut Doc, rng: &mut StdRng) {
+        self.substitute(doc, rng)
+    }
+
+    fn get_action(&self) -> Action {
+        Action::Substitute
+    }
+
+    fn get_aug_params_word(&self) -> &AugCountParams {
+        &self.aug_params_word
+    }
+
+    fn get_flag_use_model_in_sampling_words(&self) -> bool {
+        true
+    }
+
+    fn get_min_chars(&self) -> Option<usize> {
+        self.min_chars
+    }
+
+    fn get_model(&self) -> &WordEmbsModel {
+        self.model.as_ref()
+    }
+
+    fn get_stopwords(&self) -> Option<&Stopwords> {
+        self.stopwords.as_ref().as_ref()
+    }
+}
+
+impl WordAugmentor<WordEmbsModel> for WordEmbsAugmentor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn create_model() -> WordEmbsModel {
+        let mut model = WordEmbsModel::new(String::from("test_res/embs_glove.txt"), None, None, 1);
+        model.load_model().unwrap();
+        model
+    }
+
+    #[test]
+    fn test_substitute_by_nearest_neighbours() {
+        let aug = WordEmbsAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(None),
+        );
+        let input_string = String::from("The king ate an apple");
+        let mut doc = Doc::new(&input_string);
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("The queen ate an fruit")
+        );
+        assert_eq!(doc.get_changed_count(), 2);
+    }
+
+    #[test]
+    fn test_substitute_with_stopwords() {
+        let aug = WordEmbsAugmentor::new(
+            AugCountParams::new(None, None, Some(1.0)),
+            None,
+            Arc::new(create_model()),
+            Arc::new(Some(Stopwords::from(HashSet::from([String::from("king")])))),
+        );
+        let mut doc = Doc::new(&String::from("king and apple"));
+        let mut rng: StdRng = SeedableRng::from_entropy();
+        aug.augment(&mut doc, &mut rng);
+        assert_eq!(doc.get_augmented_string(), String::from("king and fruit"));
+        assert_eq!(doc.get_changed_count(), 1);
+    }
+}
@@ -0,0 +1,349 @@
+//! Augmentation of text files for command-line binary: read inputs, augment them in batches
+//! with any augmenter, created from config, and write `n` variants per input
+
+use std::error::Error;
+use std::io::{BufRead, BufReader, Read, Write};
+
+use serde_json::{Map, Value};
+
+use crate::api::DynApiClass;
+use crate::utils;
+
+/// Format of input and output
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputFormat {
+    /// Every line is an input
+    Text,
+    /// Every line is JSON object, its `field` is augmented, other fields are kept
+    Jsonl,
+    /// Column `field` of every row is augmented, other columns are kept
+    Csv,
+}
+
+impl InputFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(InputFormat::Text),
+            "jsonl" => Some(InputFormat::Jsonl),
+            "csv" => Some(InputFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+pub struct CliOptions {
+    pub format: InputFormat,
+    /// JSONL field or CSV column (name or 0-based index) to augment
+    pub field: String,
+    /// First row of CSV input is header
+    pub has_header: bool,
+    /// Number of variants per input
+    pub n: usize,
+    pub n_threads: usize,
+    /// Number of inputs, augmented at once
+    pub batch_size: usize,
+}
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+/// Augment all `readers` one after another and write variants to `writer`
+///
+/// CSV files must have the same columns, header is taken from the first one
+pub fn augment_inputs(
+    api: &dyn DynApiClass,
+    readers: Vec<Box<dyn Read>>,
+    writer: &mut dyn Write,
+    options: &CliOptions,
+) -> CliResult<()> {
+    match options.format {
+        InputFormat::Text => augment_text(api, readers, writer, options),
+        InputFormat::Jsonl => augment_jsonl(api, readers, writer, options),
+        InputFormat::Csv => augment_csv(api, readers, writer, options),
+    }
+}
+
+fn read_lines(readers: Vec<Box<dyn Read>>) -> impl Iterator<Item = std::io::Result<String>> {
+    readers
+        .into_iter()
+        .flat_map(|reader| BufReader::new(reader).lines())
+}
+
+fn augment_text(
+    api: &dyn DynApiClass,
+    readers: Vec<Box<dyn Read>>,
+    writer: &mut dyn Write,
+    options: &CliOptions,
+) -> CliResult<()> {
+    let records = read_lines(readers).map(|line| Ok(((), line?)));
+    augment_records(api, records, options, |_, variant| {
+        writeln!(writer, "{}", variant)?;
+        Ok(())
+    })
+}
+
+fn augment_jsonl(
+    api: &dyn DynApiClass,
+    readers: Vec<Box<dyn Read>>,
+    writer: &mut dyn Write,
+    options: &CliOptions,
+) -> CliResult<()> {
+    let field = &options.field;
+    let records = read_lines(readers)
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|(idx, line)| -> CliResult<(Map<String, Value>, String)> {
+            let object: Map<String, Value> =
+                serde_json::from_str(&line?).map_err(|err| format!("line {}: {}", idx + 1, err))?;
+            let text = match object.get(field) {
+                Some(Value::String(text)) => text.clone(),
+                _ => return Err(format!("line {}: no string field '{}'", idx + 1, field).into()),
+            };
+            Ok((object, text))
+        });
+    augment_records(api, records, options, |object, variant| {
+        let mut object = object.clone();
+        object.insert(field.clone(), Value::String(variant));
+        writeln!(writer, "{}", serde_json::to_string(&object)?)?;
+        Ok(())
+    })
+}
+
+fn augment_csv(
+    api: &dyn DynApiClass,
+    readers: Vec<Box<dyn Read>>,
+    writer: &mut dyn Write,
+    options: &CliOptions,
+) -> CliResult<()> {
+    let mut csv_readers: Vec<_> = readers
+        .into_iter()
+        .map(|reader| {
+            csv::ReaderBuilder::new()
+                .has_headers(options.has_header)
+                .flexible(true)
+                .from_reader(reader)
+        })
+        .collect();
+    let mut csv_writer = csv::WriterBuilder::new().flexible(true).from_writer(writer);
+    let headers = match csv_readers.first_mut() {
+        Some(csv_reader) if options.has_header => Some(csv_reader.headers()?.clone()),
+        _ => None,
+    };
+    let column = match (options.field.parse::<usize>(), &headers) {
+        (Ok(column), _) => column,
+        (Err(_), Some(headers)) => headers
+            .iter()
+            .position(|name| name == options.field)
+            .ok_or_else(|| format!("no column '{}' in header", options.field))?,
+        (Err(_), None) => return Err("column must be an index, if CSV has no header".into()),
+    };
+    if let Some(headers) = &headers {
+        csv_writer.write_record(headers)?;
+    }
+
+    let records = csv_readers
+        .into_iter()
+        .flat_map(|csv_reader| csv_reader.into_records())
+        .map(|record| -> CliResult<(csv::StringRecord, String)> {
+            let record = record?;
+            let text = record
+                .get(column)
+                .ok_or_else(|| format!("no column {} in row {:?}", column, record))?
+                .to_string();
+            Ok((record, text))
+        });
+    augment_records(api, records, options, |record, variant| {
+        let row = record
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| if idx == column { &variant } else { value });
+        csv_writer.write_record(row)?;
+        Ok(())
+    })?;
+    csv_writer.flush()?;
+    Ok(())
+}
+
+/// Augment texts of records in batches of `batch_size`, `write_variant` gets record with every of its variants
+///
+/// Every variant gets RNG by its index among all variants, so output doesn't depend on `batch_size`
+fn augment_records<R>(
+    api: &dyn DynApiClass,
+    records: impl Iterator<Item = CliResult<(R, String)>>,
+    options: &CliOptions,
+    mut write_variant: impl FnMut(&R, String) -> CliResult<()>,
+) -> CliResult<()> {
+    let seed = api.next_seed();
+    let mut first_idx = 0;
+    let mut batch = Vec::with_capacity(options.batch_size);
+    for record in records {
+        batch.push(record?);
+        if batch.len() == options.batch_size {
+            first_idx = augment_batch(
+                api,
+                &mut batch,
+                seed,
+                first_idx,
+                options,
+                &mut write_variant,
+            )?;
+        }
+    }
+    augment_batch(
+        api,
+        &mut batch,
+        seed,
+        first_idx,
+        options,
+        &mut write_variant,
+    )?;
+    Ok(())
+}
+
+/// Augment every text of batch `n` times in one call, so threads are loaded evenly
+///
+/// `first_idx` - index of the first variant of batch among all variants,
+/// returns index of the first variant of the next batch
+fn augment_batch<R>(
+    api: &dyn DynApiClass,
+    batch: &mut Vec<(R, String)>,
+    seed: u64,
+    first_idx: usize,
+    options: &CliOptions,
+    write_variant: &mut impl FnMut(&R, String) -> CliResult<()>,
+) -> CliResult<usize> {
+    if batch.is_empty() || options.n == 0 {
+        batch.clear();
+        return Ok(first_idx);
+    }
+    let inputs: Vec<String> = batch
+        .iter()
+        .flat_map(|(_, text)| std::iter::repeat_n(text.clone(), options.n))
+        .collect();
+    let next_idx = first_idx + inputs.len();
+    let variants = api.augment_list(
+        inputs,
+        options.n_threads,
+        Some(utils::offset_seed(seed, first_idx)),
+    );
+    for ((record, _), record_variants) in batch.iter().zip(variants.chunks(options.n)) {
+        for variant in record_variants {
+            write_variant(record, variant.clone())?;
+        }
+    }
+    batch.clear();
+    Ok(next_idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{AugmenterConfig, ConfigFormat};
+
+    fn substitute_api() -> Box<dyn DynApiClass> {
+        let config = r#"{
+            "type": "random_word", "action": "substitute",
+            "aug_min_word": 1, "aug_max_word": 10, "aug_p_word": 1.0,
+            "target_map_words": {"cat": ["dog"]}, "seed": 7
+        }"#;
+        AugmenterConfig::parse(config, ConfigFormat::Json)
+            .unwrap()
+            .build_api()
+            .unwrap()
+    }
+
+    fn run(format: InputFormat, field: &str, inputs: &[&str], n: usize) -> CliResult<String> {
+        let options = CliOptions {
+            format,
+            field: String::from(field),
+            has_header: true,
+            n,
+            n_threads: 2,
+            batch_size: 2,
+        };
+        let readers = inputs
+            .iter()
+            .map(|input| Box::new(std::io::Cursor::new(input.as_bytes().to_vec())) as Box<dyn Read>)
+            .collect();
+        let mut output = Vec::new();
+        augment_inputs(substitute_api().as_ref(), readers, &mut output, &options)?;
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_text() {
+        let output = run(InputFormat::Text, "text", &["my cat\nno pets\n", "cat"], 2).unwrap();
+        assert_eq!(output, "my dog\nmy dog\nno pets\nno pets\ndog\ndog\n");
+    }
+
+    #[test]
+    fn test_jsonl() {
+        let input = "{\"id\": 1, \"text\": \"my cat\"}\n\n{\"id\": 2, \"text\": \"cat\"}\n";
+        let output = run(InputFormat::Jsonl, "text", &[input], 1).unwrap();
+        assert_eq!(
+            output,
+            "{\"id\":1,\"text\":\"my dog\"}\n{\"id\":2,\"text\":\"dog\"}\n"
+        );
+        let error = run(InputFormat::Jsonl, "body", &[input], 1).unwrap_err();
+        assert_eq!(error.to_string(), "line 1: no string field 'body'");
+    }
+
+    #[test]
+    fn test_csv() {
+        let first = "id,text\n1,my cat\n2,\"cat, cat\"\n";
+        let second = "id,text\n3,cat\n";
+        let output = run(InputFormat::Csv, "text", &[first, second], 1).unwrap();
+        assert_eq!(output, "id,text\n1,my dog\n2,\"dog, dog\"\n3,dog\n");
+        assert_eq!(
+            run(InputFormat::Csv, "1", &[second], 2).unwrap(),
+            "id,text\n3,dog\n3,dog\n"
+        );
+        assert!(run(InputFormat::Csv, "body", &[first], 1).is_err());
+    }
+
+    #[test]
+    fn test_missing_model_file() {
+        for config in [
+            r#"{"type": "keyboard", "action": "substitute", "include_special_char": false,
+                "include_numeric": false, "include_upper_case": true,
+                "model_path": "test_res/missing.json", "max_distance": 1.5}"#,
+            r#"{"type": "synonym", "aug_p_word": 0.3, "dict_of_path": "test_res/missing.json"}"#,
+            r#"{"type": "tfidf", "action": "substitute", "top_k": 5, "model_path": "test_res/missing.json"}"#,
+        ] {
+            let config = AugmenterConfig::parse(config, ConfigFormat::Json).unwrap();
+            let error = config.build_api().err().unwrap();
+            assert!(error.to_string().starts_with("test_res/missing.json: "));
+        }
+    }
+
+    #[test]
+    fn test_same_output_for_any_batch_size() {
+        let config = r#"{"type": "random_word", "action": "swap", "aug_p_word": 0.5, "seed": 7}"#;
+        let input = "the quick brown fox\njumps over the dog\nthe lazy dog\nand runs away\n";
+        let outputs: Vec<String> = [1, 2, 3, 10]
+            .iter()
+            .map(|batch_size| {
+                let api = AugmenterConfig::parse(config, ConfigFormat::Json)
+                    .unwrap()
+                    .build_api()
+                    .unwrap();
+                let options = CliOptions {
+                    format: InputFormat::Text,
+                    field: String::from("text"),
+                    has_header: true,
+                    n: 3,
+                    n_threads: 2,
+                    batch_size: *batch_size,
+                };
+                let reader = Box::new(std::io::Cursor::new(input.as_bytes().to_vec()));
+                let mut output = Vec::new();
+                augment_inputs(api.as_ref(), vec![reader], &mut output, &options).unwrap();
+                String::from_utf8(output).unwrap()
+            })
+            .collect();
+        assert_eq!(outputs[0].lines().count(), 12);
+        assert_ne!(outputs[0].lines().next(), Some("the quick brown fox"));
+        for output in &outputs[1..] {
+            assert_eq!(output, &outputs[0]);
+        }
+    }
+}
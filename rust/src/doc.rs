@@ -1,7 +1,14 @@
 mod doc;
+mod protected;
 mod token;
 mod token_handler;
+mod tokenizer;
 
-pub use doc::Doc;
+pub use doc::{Doc, SpanAlignment};
+pub use protected::ProtectedSpans;
 pub use token::{Token, TokenType};
 pub use token_handler::TokenHandler;
+pub use tokenizer::{
+    tokenizer_from_name, AlphanumericTokenizer, RegexTokenizer, Tokenizer, UnicodeWordTokenizer,
+    WhitespaceTokenizer,
+};
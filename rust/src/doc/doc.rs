@@ -1,8 +1,13 @@
+use super::protected::ProtectedSpans;
 use super::token::{Token, TokenType};
 use super::token_handler::TokenHandler;
+use super::tokenizer::{AlphanumericTokenizer, Tokenizer};
 use std::sync::Arc;
 const RESERVE_CAPACITY_TO_INSERT_OPERATIONS: usize = 5;
 
+/// Char span [start, end) of token in original text -> its char span in augmented text
+pub type SpanAlignment = ((usize, usize), (usize, usize));
+
 /// Doc, main struct to store each token and perform operations on them
 pub struct Doc {
     tokens: Vec<TokenHandler>,
@@ -11,16 +16,46 @@ pub struct Doc {
 
 impl Doc {
     pub fn new(input: &String) -> Self {
-        let tokens = Doc::tokenize(input);
-        let changed_count = 0;
+        Doc::new_with_tokenizer(input, &AlphanumericTokenizer)
+    }
+
+    pub fn from_arc(input: Arc<String>) -> Self {
+        Doc::new_with_tokenizer(input.as_ref(), &AlphanumericTokenizer)
+    }
+
+    pub fn from_arc_with_tokenizer(input: Arc<String>, tokenizer: &dyn Tokenizer) -> Self {
+        Doc::new_with_tokenizer(input.as_ref(), tokenizer)
+    }
+
+    /// Create Doc from pre-tokenized input with label (e.g. BIO/NER tag) per token
+    ///
+    /// Tokens are separated by single space. Token without alphanumeric chars is SpecSymbolToken.
+    /// Tokens must be non-empty and without whitespace, otherwise `get_labeled_tokens` doesn't keep them
+    pub fn from_labeled_tokens(tokens: &[String], labels: &[String]) -> Self {
+        let mut handlers =
+            Vec::with_capacity(tokens.len() * 2 + RESERVE_CAPACITY_TO_INSERT_OPERATIONS);
+        for (idx, (token, label)) in tokens.iter().zip(labels.iter()).enumerate() {
+            if idx > 0 {
+                handlers.push(TokenHandler::new(TokenType::SpaceToken, String::from(" ")));
+            }
+            let kind = match token.chars().any(char::is_alphanumeric) {
+                true => TokenType::WordToken,
+                false => TokenType::SpecSymbolToken,
+            };
+            handlers.push(TokenHandler::new_labeled(
+                kind,
+                token.clone(),
+                label.clone(),
+            ));
+        }
         Doc {
-            tokens,
-            changed_count,
+            tokens: handlers,
+            changed_count: 0,
         }
     }
 
-    pub fn from_arc(input: Arc<String>) -> Self {
-        let tokens = Doc::tokenize(input.as_ref());
+    pub fn new_with_tokenizer(input: &str, tokenizer: &dyn Tokenizer) -> Self {
+        let tokens = Doc::tokenize(input, tokenizer);
         let changed_count = 0;
         Doc {
             tokens,
@@ -28,38 +63,12 @@ impl Doc {
         }
     }
 
-    /// Split input string to vector of different tokens
-    ///
-    /// If it alphanumeric -> WordToken, if it's space -> SpaceToken, else SpecSymbolToken
-    fn tokenize(text: &String) -> Vec<TokenHandler> {
-        let mut pos: usize = 0;
+    /// Split input string to vector of different tokens with passed tokenizer
+    fn tokenize(text: &str, tokenizer: &dyn Tokenizer) -> Vec<TokenHandler> {
+        let tokens = tokenizer.tokenize(text);
         let mut res: Vec<TokenHandler> =
-            Vec::with_capacity(text.len() + RESERVE_CAPACITY_TO_INSERT_OPERATIONS);
-        for (idx, c) in text.match_indices(|c| !char::is_alphanumeric(c)) {
-            let previous_word = text.get(pos..idx);
-            if let Some(word) = previous_word {
-                if word.len() > 0 {
-                    res.push(TokenHandler::new(TokenType::WordToken, String::from(word)));
-                    pos = idx;
-                }
-            }
-            if c.len() > 0 {
-                let flag = c.chars().next().unwrap().is_whitespace();
-                let kind = match flag {
-                    true => TokenType::SpaceToken,
-                    false => TokenType::SpecSymbolToken,
-                };
-                res.push(TokenHandler::new(kind, String::from(c)));
-                pos += c.len();
-            }
-        }
-        let remains = text.get(pos..);
-        if let Some(value) = remains {
-            if value.len() > 0 {
-                res.push(TokenHandler::new(TokenType::WordToken, String::from(value)));
-            }
-        }
-        res.shrink_to(res.len() + RESERVE_CAPACITY_TO_INSERT_OPERATIONS);
+            Vec::with_capacity(tokens.len() + RESERVE_CAPACITY_TO_INSERT_OPERATIONS);
+        res.extend(tokens.into_iter().map(TokenHandler::from_token));
         res
     }
 
@@ -80,7 +89,11 @@ impl Doc {
     ) -> Vec<(usize, &mut TokenHandler)> {
         let mut word_tokens = Vec::with_capacity(self.tokens.len());
         for (idx, token) in self.tokens.iter_mut().enumerate() {
-            let token_type = token.get_original().kind();
+            // Removed by previous step of pipeline, nothing to augment
+            if !token.has_original() || token.get_current().token().is_empty() {
+                continue;
+            }
+            let token_type = token.get_current().kind();
             match (token_type, include_special_char) {
                 (TokenType::WordToken, _) => word_tokens.push((idx, token)),
                 (TokenType::SpecSymbolToken, true) => word_tokens.push((idx, token)),
@@ -99,6 +112,33 @@ impl Doc {
             .collect()
     }
 
+    /// Mark tokens, that intersect with protected spans of original text
+    pub fn protect_spans(&mut self, protected_spans: &ProtectedSpans) {
+        let original = Doc::concatenate_tokens(self.get_original_tokens());
+        let spans = protected_spans.find_spans(&original);
+        if spans.is_empty() {
+            return;
+        }
+        let mut token_start = 0;
+        for token in self.tokens.iter_mut() {
+            let token_end = token_start + token.get_original().byte_len();
+            let intersects = spans
+                .iter()
+                .any(|(start, end)| token_start < *end && *start < token_end);
+            if token_end > token_start && intersects {
+                token.set_protected();
+            }
+            token_start = token_end;
+        }
+    }
+
+    /// Check if token with index `idx` is protected from augmentation
+    pub fn is_protected(&self, idx: usize) -> bool {
+        self.tokens
+            .get(idx)
+            .is_some_and(|token| token.is_protected())
+    }
+
     /// Swap two tokens by their indexes
     pub fn perform_swap_by_idx(&mut self, idx_a: usize, idx_b: usize) {
         let tokens_len = self.tokens.len();
@@ -111,6 +151,83 @@ impl Doc {
             a_token_handler.change(b_token_kind, b_token);
             let b_token_handler = self.tokens.get_mut(idx_b).unwrap();
             b_token_handler.change(a_token_kind, a_token);
+
+            // Source span moves together with token, so alignment follows it
+            let a_span = self.get_source_span(idx_a);
+            let b_span = self.get_source_span(idx_b);
+            self.tokens[idx_a].set_source_span(b_span);
+            self.tokens[idx_b].set_source_span(a_span);
+
+            // Label moves together with token
+            let a_label = self.tokens[idx_a].get_label().cloned();
+            let b_label = self.tokens[idx_b].get_label().cloned();
+            if let (Some(a_label), Some(b_label)) = (a_label, b_label) {
+                self.tokens[idx_a].change_label(b_label);
+                self.tokens[idx_b].change_label(a_label);
+            }
+        }
+    }
+
+    /// Char span of original text, token with index `idx` came from
+    fn get_source_span(&self, idx: usize) -> (usize, usize) {
+        if let Some(span) = self.tokens[idx].get_source_span() {
+            return span;
+        }
+        let start = self.tokens[..idx]
+            .iter()
+            .map(|token| token.get_original().utf8_len())
+            .sum();
+        (start, start + self.tokens[idx].get_original().utf8_len())
+    }
+
+    /// Insert new token before token with index `idx` (or to the end, if `idx` out of range)
+    pub fn perform_insert_by_idx(&mut self, idx: usize, kind: TokenType, token: String) {
+        let idx = usize::min(idx, self.tokens.len());
+        self.tokens
+            .insert(idx, TokenHandler::new_inserted(kind, token));
+    }
+
+    /// Split token by its index into two word tokens, separated by space token
+    ///
+    /// Token at `idx` becomes `left`, `right` is inserted at `idx + 2`.
+    /// If token is labeled, `right` gets continuation of its label
+    pub fn perform_split_by_idx(&mut self, idx: usize, left: String, right: String) {
+        if let Some(token_handler) = self.tokens.get_mut(idx) {
+            token_handler.change(TokenType::WordToken, left);
+            let label = token_handler
+                .get_label()
+                .map(|x| Doc::continuation_label(x));
+            self.perform_insert_by_idx(idx + 1, TokenType::SpaceToken, String::from(" "));
+            self.perform_insert_by_idx(idx + 2, TokenType::WordToken, right);
+            self.tokens[idx + 1].set_joined();
+            self.tokens[idx + 2].set_joined();
+            if let Some(label) = label {
+                self.tokens[idx + 2].change_label(label);
+            }
+        }
+    }
+
+    /// Label for the next part of the same entity: 'B-LOC' -> 'I-LOC', other labels are kept
+    fn continuation_label(label: &str) -> String {
+        match label.strip_prefix("B-") {
+            Some(entity) => format!("I-{}", entity),
+            None => label.to_string(),
+        }
+    }
+
+    /// Replace tokens in range [start, end) with one word token
+    ///
+    /// Token at `start` becomes `token`, other tokens of range become empty
+    pub fn perform_replace_range(&mut self, start: usize, end: usize, token: String) {
+        let end = usize::min(end, self.tokens.len());
+        if start >= end {
+            return;
+        }
+        self.tokens[start].change(TokenType::WordToken, token);
+        for token_handler in self.tokens[start + 1..end].iter_mut() {
+            let kind = token_handler.get_current().kind().clone();
+            token_handler.change(kind, String::new());
+            token_handler.set_joined();
         }
     }
 
@@ -118,7 +235,11 @@ impl Doc {
     pub fn get_word_tokens_count(&self, include_special_char: bool) -> usize {
         let mut count = 0;
         for token in self.tokens.iter() {
-            let token_type = token.get_original().kind();
+            // Removed by previous step of pipeline, nothing to augment
+            if !token.has_original() || token.get_current().token().is_empty() {
+                continue;
+            }
+            let token_type = token.get_current().kind();
             match (token_type, include_special_char) {
                 (TokenType::WordToken, _) => count += 1,
                 (TokenType::SpecSymbolToken, true) => count += 1,
@@ -130,7 +251,18 @@ impl Doc {
 
     /// Get original tokens (before augmentation)
     pub fn get_original_tokens(&self) -> Vec<&Token> {
-        self.tokens.iter().map(|ch| ch.get_original()).collect()
+        self.tokens
+            .iter()
+            .filter(|ch| !ch.is_inserted())
+            .map(|ch| ch.get_original())
+            .collect()
+    }
+
+    /// Get tokens to augment, one per token of Doc: indexes of result are indexes of Doc
+    ///
+    /// Inserted tokens, that aren't committed yet, are empty
+    pub fn get_tokens(&self) -> Vec<&Token> {
+        self.tokens.iter().map(|ch| ch.get_current()).collect()
     }
 
     /// Get latest tokens (after augmentation, if it was)
@@ -143,6 +275,57 @@ impl Doc {
         Doc::concatenate_tokens(self.get_augmented_tokens())
     }
 
+    /// Char spans of every token in original text and in augmented text
+    ///
+    /// Inserted token has empty original span, removed token has empty augmented span.
+    /// Split token and replaced range of tokens have one span: 'New York' -> 'NYC' is (4, 12) -> (4, 7).
+    /// Swapped token keeps span of original text, it came from
+    pub fn get_alignment(&self) -> Vec<SpanAlignment> {
+        let mut original_pos = 0;
+        let mut augmented_pos = 0;
+        let mut alignment: Vec<SpanAlignment> = Vec::with_capacity(self.tokens.len());
+        for token in self.tokens.iter() {
+            let original_len = token.get_original().utf8_len();
+            let augmented_len = token.get_latest().utf8_len();
+            let source_span = token
+                .get_source_span()
+                .unwrap_or((original_pos, original_pos + original_len));
+            match alignment.last_mut() {
+                Some((original, augmented)) if token.is_joined() => {
+                    *original = (
+                        usize::min(original.0, source_span.0),
+                        usize::max(original.1, source_span.1),
+                    );
+                    augmented.1 += augmented_len;
+                }
+                _ => alignment.push((source_span, (augmented_pos, augmented_pos + augmented_len))),
+            }
+            original_pos += original_len;
+            augmented_pos += augmented_len;
+        }
+        alignment
+    }
+
+    /// Augmented tokens (without spaces) with their labels
+    ///
+    /// Deleted tokens are dropped, inserted tokens get `default_label`.
+    /// If augmented token contains spaces (e.g. multi-word synonym), it's split,
+    /// and every next part gets continuation of the label
+    pub fn get_labeled_tokens(&self, default_label: &str) -> Vec<(String, String)> {
+        let mut labeled = Vec::with_capacity(self.tokens.len());
+        for token in self.tokens.iter() {
+            let mut label = match token.get_label() {
+                Some(label) => label.clone(),
+                None => String::from(default_label),
+            };
+            for part in token.get_latest().token().split_whitespace() {
+                labeled.push((String::from(part), label.clone()));
+                label = Doc::continuation_label(&label);
+            }
+        }
+        labeled
+    }
+
     /// Get number of changes
     pub fn get_changed_count(&self) -> usize {
         self.changed_count
@@ -153,8 +336,18 @@ impl Doc {
         self.changed_count = value
     }
 
-    /// Clear all changes
+    /// Make current changes the base for the next augmentation (next step of pipeline)
+    ///
+    /// Original text is kept: alignment and `set_to_original` still refer to it
+    pub fn commit_changes(&mut self) {
+        for token in self.tokens.iter_mut() {
+            token.commit();
+        }
+    }
+
+    /// Clear all changes and remove inserted tokens
     pub fn set_to_original(&mut self) -> () {
+        self.tokens.retain(|token| !token.is_inserted());
         for token in self.tokens.iter_mut() {
             token.set_to_original();
         }
@@ -189,7 +382,10 @@ mod tests {
             TokenHandler::new(TokenType::SpecSymbolToken, String::from(".")),
             TokenHandler::new(TokenType::SpaceToken, String::from(" ")),
         ];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -214,7 +410,10 @@ mod tests {
             TokenHandler::new(TokenType::SpaceToken, String::from(" ")),
             TokenHandler::new(TokenType::SpecSymbolToken, String::from(".")),
         ];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -232,7 +431,10 @@ mod tests {
             TokenHandler::new(TokenType::SpaceToken, String::from(" ")),
             TokenHandler::new(TokenType::WordToken, String::from("tokens")),
         ];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -250,7 +452,10 @@ mod tests {
             TokenHandler::new(TokenType::SpaceToken, String::from(" ")),
             TokenHandler::new(TokenType::WordToken, String::from("токены")),
         ];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -267,7 +472,10 @@ mod tests {
             TokenType::WordToken,
             String::from("example"),
         )];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -285,7 +493,10 @@ mod tests {
             TokenHandler::new(TokenType::SpecSymbolToken, String::from("@")),
             TokenHandler::new(TokenType::SpecSymbolToken, String::from("#")),
         ];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -303,7 +514,10 @@ mod tests {
             TokenHandler::new(TokenType::SpaceToken, String::from(" ")),
             TokenHandler::new(TokenType::SpaceToken, String::from(" ")),
         ];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -317,7 +531,10 @@ mod tests {
     fn test_tokenize_empty() {
         let input_str = String::from("");
         let expected_handlers: Vec<TokenHandler> = vec![];
-        assert_eq!(Doc::tokenize(&input_str), expected_handlers);
+        assert_eq!(
+            Doc::tokenize(&input_str, &AlphanumericTokenizer),
+            expected_handlers
+        );
 
         let mut doc = Doc::new(&input_str);
         assert_eq!(doc.tokens, expected_handlers);
@@ -404,6 +621,260 @@ mod tests {
         assert_eq!(result, expected_str);
     }
 
+    #[test]
+    fn test_insert_tokens() {
+        let mut doc = Doc::new(&String::from("Test example!"));
+        doc.perform_insert_by_idx(2, TokenType::SpaceToken, String::from(" "));
+        doc.perform_insert_by_idx(2, TokenType::WordToken, String::from("new"));
+        doc.perform_insert_by_idx(100, TokenType::SpecSymbolToken, String::from("?"));
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("Test new example!?")
+        );
+        assert_eq!(doc.get_word_tokens_count(false), 2);
+        assert_eq!(doc.get_word_indexes(false), vec![0, 4]);
+        assert_eq!(doc.get_original_tokens().len(), 4);
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), String::from("Test example!"));
+        assert_eq!(doc.tokens.len(), 4);
+    }
+
+    #[test]
+    fn test_split_token() {
+        let mut doc = Doc::new(&String::from("Test example!"));
+        doc.perform_split_by_idx(2, String::from("exa"), String::from("mple"));
+        assert_eq!(doc.get_augmented_string(), String::from("Test exa mple!"));
+        doc.perform_split_by_idx(0, String::from("T"), String::from("est"));
+        assert_eq!(doc.get_augmented_string(), String::from("T est exa mple!"));
+        doc.perform_split_by_idx(100, String::from("a"), String::from("b"));
+        assert_eq!(doc.get_augmented_string(), String::from("T est exa mple!"));
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), String::from("Test example!"));
+    }
+
+    #[test]
+    fn test_replace_range() {
+        let mut doc = Doc::new(&String::from("I love New York!"));
+        doc.perform_replace_range(4, 7, String::from("NYC"));
+        assert_eq!(doc.get_augmented_string(), String::from("I love NYC!"));
+        doc.perform_replace_range(0, 1, String::from("We"));
+        assert_eq!(doc.get_augmented_string(), String::from("We love NYC!"));
+        doc.perform_replace_range(5, 5, String::from("a"));
+        doc.perform_replace_range(100, 101, String::from("a"));
+        assert_eq!(doc.get_augmented_string(), String::from("We love NYC!"));
+
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), String::from("I love New York!"));
+    }
+
+    #[test]
+    fn test_alignment() {
+        let mut doc = Doc::new(&String::from("Мой New York!"));
+        doc.perform_insert_by_idx(1, TokenType::WordToken, String::from("big"));
+        doc.perform_insert_by_idx(1, TokenType::SpaceToken, String::from(" "));
+        doc.perform_swap_by_idx(4, 6);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("Мой big York New!")
+        );
+        assert_eq!(
+            doc.get_alignment(),
+            vec![
+                ((0, 3), (0, 3)),
+                ((3, 3), (3, 4)),
+                ((3, 3), (4, 7)),
+                ((3, 4), (7, 8)),
+                ((8, 12), (8, 12)),
+                ((7, 8), (12, 13)),
+                ((4, 7), (13, 16)),
+                ((12, 13), (16, 17)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alignment_swap() {
+        let mut doc = Doc::new(&String::from("Anna lives in Paris"));
+        doc.perform_swap_by_idx(0, 2);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("lives Anna in Paris")
+        );
+        assert_eq!(
+            doc.get_alignment(),
+            vec![
+                ((5, 10), (0, 5)),
+                ((4, 5), (5, 6)),
+                ((0, 4), (6, 10)),
+                ((10, 11), (10, 11)),
+                ((11, 13), (11, 13)),
+                ((13, 14), (13, 14)),
+                ((14, 19), (14, 19)),
+            ]
+        );
+        // Swap of already moved token and committed swap keep the source
+        doc.perform_swap_by_idx(2, 6);
+        doc.commit_changes();
+        doc.perform_swap_by_idx(0, 4);
+        assert_eq!(
+            doc.get_augmented_string(),
+            String::from("in Paris lives Anna")
+        );
+        let alignment = doc.get_alignment();
+        assert_eq!(alignment[0], ((11, 13), (0, 2)));
+        assert_eq!(alignment[2], ((14, 19), (3, 8)));
+        assert_eq!(alignment[4], ((5, 10), (9, 14)));
+        assert_eq!(alignment[6], ((0, 4), (15, 19)));
+        doc.set_to_original();
+        assert_eq!(doc.get_alignment()[0], ((0, 4), (0, 4)));
+    }
+
+    #[test]
+    fn test_alignment_replace_range() {
+        let mut doc = Doc::new(&String::from("I love New York!"));
+        doc.perform_replace_range(4, 7, String::from("NYC"));
+        assert_eq!(doc.get_augmented_string(), String::from("I love NYC!"));
+        assert_eq!(
+            doc.get_alignment(),
+            vec![
+                ((0, 1), (0, 1)),
+                ((1, 2), (1, 2)),
+                ((2, 6), (2, 6)),
+                ((6, 7), (6, 7)),
+                ((7, 15), (7, 10)),
+                ((15, 16), (10, 11)),
+            ]
+        );
+        doc.set_to_original();
+        assert_eq!(doc.get_alignment().len(), 8);
+    }
+
+    #[test]
+    fn test_alignment_split() {
+        let mut doc = Doc::new(&String::from("Мой New York!"));
+        doc.perform_split_by_idx(0, String::from("М"), String::from("ой"));
+        doc.perform_split_by_idx(6, String::from("Yo"), String::from("rk"));
+        assert_eq!(doc.get_augmented_string(), String::from("М ой New Yo rk!"));
+        assert_eq!(
+            doc.get_alignment(),
+            vec![
+                ((0, 3), (0, 4)),
+                ((3, 4), (4, 5)),
+                ((4, 7), (5, 8)),
+                ((7, 8), (8, 9)),
+                ((8, 12), (9, 14)),
+                ((12, 13), (14, 15)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commit_changes() {
+        let mut doc = Doc::new(&String::from("Мой New York!"));
+        doc.perform_replace_range(2, 5, String::from("NYC"));
+        doc.perform_split_by_idx(0, String::from("М"), String::from("ой"));
+        doc.commit_changes();
+        // Committed tokens are augmented by next step, removed ones are skipped
+        let words: Vec<&String> = doc
+            .get_word_tokens_with_indexes(false)
+            .into_iter()
+            .map(|(_, handler)| handler.get_current().token())
+            .collect();
+        assert_eq!(words, vec!["М", "ой", "NYC"]);
+        doc.perform_swap_by_idx(0, 2);
+        assert_eq!(doc.get_augmented_string(), String::from("ой М NYC!"));
+        assert_eq!(doc.get_alignment()[0], ((0, 3), (0, 4)));
+        doc.set_to_original();
+        assert_eq!(doc.get_augmented_string(), String::from("Мой New York!"));
+    }
+
+    fn labeled_doc() -> Doc {
+        let tokens: Vec<String> = ["Anna", "visited", "New", "York", "."]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        let labels: Vec<String> = ["B-PER", "O", "B-LOC", "I-LOC", "O"]
+            .iter()
+            .map(|x| x.to_string())
+            .collect();
+        Doc::from_labeled_tokens(&tokens, &labels)
+    }
+
+    fn labeled(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(token, label)| (token.to_string(), label.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_labeled_tokens() {
+        let doc = labeled_doc();
+        assert_eq!(doc.get_augmented_string(), "Anna visited New York .");
+        assert_eq!(doc.get_word_tokens_count(false), 4);
+        assert_eq!(
+            doc.get_labeled_tokens("O"),
+            labeled(&[
+                ("Anna", "B-PER"),
+                ("visited", "O"),
+                ("New", "B-LOC"),
+                ("York", "I-LOC"),
+                (".", "O")
+            ])
+        );
+    }
+
+    #[test]
+    fn test_labeled_tokens_after_augmentation() {
+        let mut doc = labeled_doc();
+        doc.perform_swap_by_idx(0, 2);
+        doc.perform_replace_range(4, 7, String::from("Los Angeles"));
+        doc.perform_split_by_idx(2, String::from("An"), String::from("na"));
+        doc.perform_insert_by_idx(0, TokenType::WordToken, String::from("Yesterday"));
+        doc.perform_insert_by_idx(1, TokenType::SpaceToken, String::from(" "));
+        assert_eq!(
+            doc.get_augmented_string(),
+            "Yesterday visited An na Los Angeles ."
+        );
+        assert_eq!(
+            doc.get_labeled_tokens("O"),
+            labeled(&[
+                ("Yesterday", "O"),
+                ("visited", "O"),
+                ("An", "B-PER"),
+                ("na", "I-PER"),
+                ("Los", "B-LOC"),
+                ("Angeles", "I-LOC"),
+                (".", "O")
+            ])
+        );
+
+        doc.set_to_original();
+        assert_eq!(
+            doc.get_labeled_tokens("O"),
+            labeled_doc().get_labeled_tokens("O")
+        );
+    }
+
+    #[test]
+    fn test_protect_spans() {
+        let protected =
+            ProtectedSpans::new(&[String::from("url"), String::from("number")], &[]).unwrap();
+        let mut doc = Doc::new(&String::from("Visit www.site.com in 2023!"));
+        doc.protect_spans(&protected);
+        let protected_tokens: Vec<&String> = (0..doc.tokens.len())
+            .filter(|idx| doc.is_protected(*idx))
+            .map(|idx| doc.tokens[idx].get_original().token())
+            .collect();
+        assert_eq!(
+            protected_tokens,
+            vec!["www", ".", "site", ".", "com", "2023"]
+        );
+        assert!(!doc.is_protected(100));
+    }
+
     #[test]
     fn test_dont_add_change_in_token_handler() {
         let doc = Doc::new(&String::from("Test example!"));
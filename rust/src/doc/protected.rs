@@ -0,0 +1,109 @@
+use regex::Regex;
+use std::error::Error;
+
+/// Built-in detectors of spans, that must stay untouched
+const DETECTORS: [(&str, &str); 6] = [
+    (
+        "url",
+        r#"(?i)\b(?:https?://|ftp://|www\.)[^\s<>"']*[^\s<>"'.,;:!?)]"#,
+    ),
+    ("email", r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+"),
+    ("number", r"[+-]?\d+(?:[.,:/-]\d+)*"),
+    ("mention", r"\B@\w+"),
+    ("hashtag", r"\B#\w+"),
+    ("placeholder", r"\{\{?[^{}\s]*\}?\}"),
+];
+
+/// Spans of text (URLs, emails, numbers, custom regex matches, ...), which tokens are never augmented
+pub struct ProtectedSpans {
+    patterns: Vec<Regex>,
+}
+
+impl ProtectedSpans {
+    /// `detectors` - names of built-in detectors:
+    /// 'url', 'email', 'number', 'mention' (@user), 'hashtag' (#tag), 'placeholder' ({var})
+    ///
+    /// `patterns` - your own regexes, every match is protected
+    pub fn new(detectors: &[String], patterns: &[String]) -> Result<Self, Box<dyn Error>> {
+        let mut compiled = Vec::with_capacity(detectors.len() + patterns.len());
+        for name in detectors {
+            let pattern = DETECTORS
+                .iter()
+                .find(|(detector, _)| detector == name)
+                .map(|(_, pattern)| *pattern)
+                .ok_or_else(|| format!("unknown protected span detector '{}'", name))?;
+            compiled.push(Regex::new(pattern)?);
+        }
+        for pattern in patterns {
+            compiled.push(Regex::new(pattern)?);
+        }
+        Ok(Self { patterns: compiled })
+    }
+
+    /// Names of all built-in detectors
+    pub fn detector_names() -> Vec<&'static str> {
+        DETECTORS.iter().map(|(name, _)| *name).collect()
+    }
+
+    /// Byte spans [start, end) of all matches in text
+    pub fn find_spans(&self, text: &str) -> Vec<(usize, usize)> {
+        let mut spans: Vec<(usize, usize)> = self
+            .patterns
+            .iter()
+            .flat_map(|pattern| pattern.find_iter(text).map(|m| (m.start(), m.end())))
+            .filter(|(start, end)| start < end)
+            .collect();
+        spans.sort_unstable();
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn protected_texts(protected: &ProtectedSpans, text: &str) -> Vec<String> {
+        protected
+            .find_spans(text)
+            .into_iter()
+            .map(|(start, end)| String::from(&text[start..end]))
+            .collect()
+    }
+
+    fn all_detectors() -> Vec<String> {
+        ProtectedSpans::detector_names()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_builtin_detectors() {
+        let protected = ProtectedSpans::new(&all_detectors(), &[]).unwrap();
+        let text =
+            "Write to john.doe@mail.com or @john, see https://example.com/a?b=1. #news {name} 3.14";
+        assert_eq!(
+            protected_texts(&protected, text),
+            vec![
+                "john.doe@mail.com",
+                "@john",
+                "https://example.com/a?b=1",
+                "1",
+                "#news",
+                "{name}",
+                "3.14",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_custom_patterns() {
+        let protected = ProtectedSpans::new(&[], &[String::from(r"[A-Z]{2,}")]).unwrap();
+        assert_eq!(
+            protected_texts(&protected, "NASA and ESA"),
+            vec!["NASA", "ESA"]
+        );
+        assert!(ProtectedSpans::new(&[String::from("phone")], &[]).is_err());
+        assert!(ProtectedSpans::new(&[], &[String::from("(")]).is_err());
+    }
+}
@@ -45,6 +45,24 @@ impl Token {
         self.token_len
     }
 
+    /// Get number of extended grapheme clusters
+    pub fn grapheme_len(&self) -> usize {
+        utils::get_graphemes_len(&self.token)
+    }
+
+    /// Get length in chars or in extended grapheme clusters
+    pub fn chars_len(&self, use_graphemes: bool) -> usize {
+        match use_graphemes {
+            true => self.grapheme_len(),
+            false => self.utf8_len(),
+        }
+    }
+
+    /// Split token to chars or to extended grapheme clusters
+    pub fn split_chars(&self, use_graphemes: bool) -> Vec<&str> {
+        utils::split_chars(&self.token, use_graphemes)
+    }
+
     /// Get bytes lenght
     pub fn byte_len(&self) -> usize {
         self.token.len()
@@ -68,4 +86,14 @@ mod tests {
         assert_eq!(token.utf8_len(), 6);
         assert_eq!(token.byte_len(), 12);
     }
+
+    #[test]
+    fn test_grapheme_len() {
+        let token = Token::new(TokenType::WordToken, String::from("cafe\u{301}"));
+        assert_eq!(token.utf8_len(), 5);
+        assert_eq!(token.grapheme_len(), 4);
+        assert_eq!(token.chars_len(true), 4);
+        assert_eq!(token.split_chars(true), vec!["c", "a", "f", "e\u{301}"]);
+        assert_eq!(token.split_chars(false).len(), 5);
+    }
 }
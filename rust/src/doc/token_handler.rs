@@ -5,6 +5,21 @@ use super::token::{Token, TokenType};
 pub struct TokenHandler {
     original_token: Token,
     changed_token: Option<Token>,
+    /// Augmented version, committed by previous step of pipeline, next steps augment it
+    committed_token: Option<Token>,
+    /// Token doesn't exist in original text, it was inserted during augmentation
+    inserted: bool,
+    /// Label of original token (e.g. NER tag), if input was labeled
+    label: Option<String>,
+    /// Label of changed token, if it differs from original one (e.g. after swap)
+    changed_label: Option<String>,
+    /// Token is inside of protected span, it must not be augmented
+    protected: bool,
+    /// Token is a part of previous one after augmentation (split or replaced range),
+    /// so they are aligned with original text together
+    joined: bool,
+    /// Char span of original text, token came from, if it was moved by swap
+    source_span: Option<(usize, usize)>,
 }
 
 impl TokenHandler {
@@ -12,6 +27,60 @@ impl TokenHandler {
         TokenHandler {
             original_token: Token::new(kind, token_str),
             changed_token: None,
+            committed_token: None,
+            inserted: false,
+            label: None,
+            changed_label: None,
+            protected: false,
+            joined: false,
+            source_span: None,
+        }
+    }
+
+    /// Wrap token, produced by tokenizer
+    pub fn from_token(token: Token) -> Self {
+        TokenHandler {
+            original_token: token,
+            changed_token: None,
+            committed_token: None,
+            inserted: false,
+            label: None,
+            changed_label: None,
+            protected: false,
+            joined: false,
+            source_span: None,
+        }
+    }
+
+    /// Create token with label, that is carried over augmentations
+    pub fn new_labeled(kind: TokenType, token_str: String, label: String) -> Self {
+        TokenHandler {
+            original_token: Token::new(kind, token_str),
+            changed_token: None,
+            committed_token: None,
+            inserted: false,
+            label: Some(label),
+            changed_label: None,
+            protected: false,
+            joined: false,
+            source_span: None,
+        }
+    }
+
+    /// Create token, that doesn't exist in original text
+    ///
+    /// Original version of it is empty, changed version - is inserted value
+    pub fn new_inserted(kind: TokenType, token_str: String) -> Self {
+        TokenHandler {
+            original_token: Token::new(kind.clone(), String::new()),
+            changed_token: Some(Token::new(kind, token_str)),
+            committed_token: None,
+            inserted: true,
+            label: None,
+            changed_label: None,
+            protected: false,
+            joined: false,
+            source_span: None,
         }
     }
 
@@ -21,27 +90,88 @@ impl TokenHandler {
     }
 
     pub fn is_changed(&self) -> bool {
-        if let Some(_) = &self.changed_token {
-            return true;
-        }
-        false
+        self.changed_token.is_some() || self.committed_token.is_some()
+    }
+
+    pub fn is_inserted(&self) -> bool {
+        self.inserted
+    }
+
+    /// Token can be augmented: it's from original text or it was committed after insertion
+    pub fn has_original(&self) -> bool {
+        !self.inserted || self.committed_token.is_some()
     }
 
     pub fn get_original(&self) -> &Token {
         &self.original_token
     }
 
+    /// Version of token, that is augmented: committed by previous step of pipeline, if it exists, else -> original
+    pub fn get_current(&self) -> &Token {
+        self.committed_token
+            .as_ref()
+            .unwrap_or(&self.original_token)
+    }
+
     /// If it has any change -> returns latest version, else -> original
     pub fn get_latest(&self) -> &Token {
         if let Some(token) = &self.changed_token {
             return token;
         }
-        &self.original_token
+        self.get_current()
+    }
+
+    /// Make changed version the one, that is augmented by next steps
+    pub fn commit(&mut self) {
+        if let Some(token) = self.changed_token.take() {
+            self.committed_token = Some(token);
+        }
+    }
+
+    pub fn is_protected(&self) -> bool {
+        self.protected
+    }
+
+    /// Mark token as protected from augmentation
+    pub fn set_protected(&mut self) {
+        self.protected = true;
+    }
+
+    pub fn is_joined(&self) -> bool {
+        self.joined
+    }
+
+    /// Mark token as a part of previous one
+    pub fn set_joined(&mut self) {
+        self.joined = true;
+    }
+
+    pub fn get_source_span(&self) -> Option<(usize, usize)> {
+        self.source_span
+    }
+
+    /// Set char span of original text, moved token came from
+    pub fn set_source_span(&mut self, span: (usize, usize)) {
+        self.source_span = Some(span);
+    }
+
+    /// Latest label of token, None if input wasn't labeled or token was inserted
+    pub fn get_label(&self) -> Option<&String> {
+        self.changed_label.as_ref().or(self.label.as_ref())
+    }
+
+    /// Set new label of this token
+    pub fn change_label(&mut self, label: String) {
+        self.changed_label = Some(label);
     }
 
     /// Clear all changes
     pub fn set_to_original(&mut self) -> () {
-        self.changed_token = None
+        self.changed_token = None;
+        self.committed_token = None;
+        self.changed_label = None;
+        self.joined = false;
+        self.source_span = None;
     }
 }
 
@@ -63,4 +193,51 @@ mod tests {
         assert!(th.is_changed());
         assert_eq!(th.get_latest().token(), "new");
     }
+
+    #[test]
+    fn test_token_handler_inserted() {
+        let th = TokenHandler::new_inserted(TokenType::WordToken, String::from("new"));
+        assert!(th.is_changed());
+        assert!(th.is_inserted());
+        assert_eq!(th.get_original().token(), "");
+        assert_eq!(th.get_latest().token(), "new");
+    }
+
+    #[test]
+    fn test_token_handler_labeled() {
+        let mut th = TokenHandler::new_labeled(
+            TokenType::WordToken,
+            String::from("Paris"),
+            String::from("B-LOC"),
+        );
+        assert_eq!(th.get_label(), Some(&String::from("B-LOC")));
+        th.change_label(String::from("O"));
+        assert_eq!(th.get_label(), Some(&String::from("O")));
+        th.set_to_original();
+        assert_eq!(th.get_label(), Some(&String::from("B-LOC")));
+
+        let th = TokenHandler::new(TokenType::WordToken, String::from("Paris"));
+        assert_eq!(th.get_label(), None);
+    }
+
+    #[test]
+    fn test_token_handler_commit() {
+        let mut th = TokenHandler::new(TokenType::WordToken, String::from("old"));
+        th.change(TokenType::WordToken, String::from("new"));
+        th.commit();
+        assert_eq!(th.get_current().token(), "new");
+        assert_eq!(th.get_original().token(), "old");
+        th.change(TokenType::WordToken, String::from("newer"));
+        assert_eq!(th.get_current().token(), "new");
+        assert_eq!(th.get_original().token(), "old");
+        assert_eq!(th.get_latest().token(), "newer");
+        th.set_to_original();
+        assert_eq!(th.get_latest().token(), "old");
+
+        let mut inserted = TokenHandler::new_inserted(TokenType::WordToken, String::from("ins"));
+        assert!(!inserted.has_original());
+        inserted.commit();
+        assert!(inserted.has_original());
+        assert_eq!(inserted.get_original().token(), "");
+    }
 }
@@ -0,0 +1,250 @@
+use super::token::{Token, TokenType};
+use regex::Regex;
+use std::error::Error;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Splits text to tokens, Doc is constructed with it
+///
+/// Concatenation of tokens must give the original text
+pub trait Tokenizer: Send + Sync {
+    fn tokenize(&self, text: &str) -> Vec<Token>;
+}
+
+/// Each char of non-word part of text is a separate token:
+/// SpaceToken, if it's whitespace, else SpecSymbolToken
+fn push_non_word_chars(text: &str, tokens: &mut Vec<Token>) {
+    for ch in text.chars() {
+        let kind = match ch.is_whitespace() {
+            true => TokenType::SpaceToken,
+            false => TokenType::SpecSymbolToken,
+        };
+        tokens.push(Token::new(kind, ch.to_string()));
+    }
+}
+
+/// Push word token and non-word chars for each (start, end) word span
+fn tokens_from_word_spans(text: &str, spans: impl Iterator<Item = (usize, usize)>) -> Vec<Token> {
+    let mut tokens = Vec::with_capacity(text.len());
+    let mut pos = 0;
+    for (start, end) in spans {
+        if start == end {
+            continue;
+        }
+        push_non_word_chars(&text[pos..start], &mut tokens);
+        tokens.push(Token::new(
+            TokenType::WordToken,
+            String::from(&text[start..end]),
+        ));
+        pos = end;
+    }
+    push_non_word_chars(&text[pos..], &mut tokens);
+    tokens
+}
+
+/// Every continuous sequence of chars, that satisfy is_word_char, is a word
+fn split_words(text: &str, is_word_char: fn(char) -> bool) -> Vec<Token> {
+    let mut pos: usize = 0;
+    let mut res: Vec<Token> = Vec::with_capacity(text.len());
+    for (idx, c) in text.match_indices(|c| !is_word_char(c)) {
+        if idx > pos {
+            res.push(Token::new(
+                TokenType::WordToken,
+                String::from(&text[pos..idx]),
+            ));
+        }
+        push_non_word_chars(c, &mut res);
+        pos = idx + c.len();
+    }
+    if pos < text.len() {
+        res.push(Token::new(TokenType::WordToken, String::from(&text[pos..])));
+    }
+    res
+}
+
+/// Default tokenizer: every continuous sequence of alphanumeric chars is a word
+///
+/// "don't" -> ["don", "'", "t"]
+pub struct AlphanumericTokenizer;
+
+impl Tokenizer for AlphanumericTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        split_words(text, char::is_alphanumeric)
+    }
+}
+
+/// Split only by whitespaces, punctuation stays inside of words
+///
+/// "U.S. e-mail" -> ["U.S.", " ", "e-mail"]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        split_words(text, |c| !c.is_whitespace())
+    }
+}
+
+/// Every match of regex is a word, other chars are spaces or special symbols
+pub struct RegexTokenizer {
+    pattern: Regex,
+}
+
+impl RegexTokenizer {
+    /// Default pattern keeps together words with inner apostrophes, hyphens and dots:
+    /// "don't", "e-mail", "U.S.", "COVID-19"
+    pub const DEFAULT_PATTERN: &'static str = r"(?:\w\.){2,}|\w+(?:['’\-.]\w+)*";
+
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            pattern: Regex::new(pattern)?,
+        })
+    }
+}
+
+impl Tokenizer for RegexTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let spans = self.pattern.find_iter(text).map(|m| (m.start(), m.end()));
+        tokens_from_word_spans(text, spans)
+    }
+}
+
+/// Unicode word boundaries (UAX#29)
+///
+/// Segment is a word, if it contains any alphanumeric char: "don't" -> ["don't"], "e-mail" -> ["e", "-", "mail"]
+pub struct UnicodeWordTokenizer;
+
+impl Tokenizer for UnicodeWordTokenizer {
+    fn tokenize(&self, text: &str) -> Vec<Token> {
+        let spans = text
+            .split_word_bound_indices()
+            .filter(|(_, segment)| segment.chars().any(char::is_alphanumeric))
+            .map(|(start, segment)| (start, start + segment.len()));
+        tokens_from_word_spans(text, spans)
+    }
+}
+
+/// Create tokenizer by its name: 'alphanumeric', 'whitespace', 'regex' or 'unicode'
+///
+/// Pattern is used only by 'regex' tokenizer, if it's None -> `RegexTokenizer::DEFAULT_PATTERN`
+pub fn tokenizer_from_name(
+    name: &str,
+    pattern: Option<&str>,
+) -> Result<Arc<dyn Tokenizer>, Box<dyn Error>> {
+    match name {
+        "alphanumeric" => Ok(Arc::new(AlphanumericTokenizer)),
+        "whitespace" => Ok(Arc::new(WhitespaceTokenizer)),
+        "regex" => {
+            let pattern = pattern.unwrap_or(RegexTokenizer::DEFAULT_PATTERN);
+            Ok(Arc::new(RegexTokenizer::new(pattern)?))
+        }
+        "unicode" => Ok(Arc::new(UnicodeWordTokenizer)),
+        _ => Err(format!("unknown tokenizer '{}'", name).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokenize(tokenizer: &dyn Tokenizer, text: &str) -> Vec<(TokenType, String)> {
+        let tokens = tokenizer.tokenize(text);
+        let concatenated: String = tokens.iter().map(|t| t.token().as_str()).collect();
+        assert_eq!(concatenated, text);
+        tokens
+            .into_iter()
+            .map(|t| (t.kind().clone(), t.token().clone()))
+            .collect()
+    }
+
+    fn words(tokenizer: &dyn Tokenizer, text: &str) -> Vec<String> {
+        tokenize(tokenizer, text)
+            .into_iter()
+            .filter(|(kind, _)| *kind == TokenType::WordToken)
+            .map(|(_, token)| token)
+            .collect()
+    }
+
+    const TEXT: &str = "I don't use e-mail in the U.S., COVID-19!";
+
+    #[test]
+    fn test_alphanumeric_tokenizer() {
+        assert_eq!(
+            words(&AlphanumericTokenizer, TEXT),
+            vec!["I", "don", "t", "use", "e", "mail", "in", "the", "U", "S", "COVID", "19"]
+        );
+        assert_eq!(
+            tokenize(&AlphanumericTokenizer, "  a!"),
+            vec![
+                (TokenType::SpaceToken, String::from(" ")),
+                (TokenType::SpaceToken, String::from(" ")),
+                (TokenType::WordToken, String::from("a")),
+                (TokenType::SpecSymbolToken, String::from("!")),
+            ]
+        );
+        assert!(AlphanumericTokenizer.tokenize("").is_empty());
+    }
+
+    #[test]
+    fn test_whitespace_tokenizer() {
+        assert_eq!(
+            words(&WhitespaceTokenizer, TEXT),
+            vec![
+                "I",
+                "don't",
+                "use",
+                "e-mail",
+                "in",
+                "the",
+                "U.S.,",
+                "COVID-19!"
+            ]
+        );
+        assert_eq!(
+            tokenize(&WhitespaceTokenizer, " привет  мир "),
+            vec![
+                (TokenType::SpaceToken, String::from(" ")),
+                (TokenType::WordToken, String::from("привет")),
+                (TokenType::SpaceToken, String::from(" ")),
+                (TokenType::SpaceToken, String::from(" ")),
+                (TokenType::WordToken, String::from("мир")),
+                (TokenType::SpaceToken, String::from(" ")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_regex_tokenizer() {
+        let tokenizer = RegexTokenizer::new(RegexTokenizer::DEFAULT_PATTERN).unwrap();
+        assert_eq!(
+            words(&tokenizer, TEXT),
+            vec!["I", "don't", "use", "e-mail", "in", "the", "U.S.", "COVID-19"]
+        );
+        let tokenizer = RegexTokenizer::new(r"\d+").unwrap();
+        assert_eq!(words(&tokenizer, "a1b22 c"), vec!["1", "22"]);
+        assert!(RegexTokenizer::new("(").is_err());
+    }
+
+    #[test]
+    fn test_unicode_tokenizer() {
+        assert_eq!(
+            words(&UnicodeWordTokenizer, TEXT),
+            vec!["I", "don't", "use", "e", "mail", "in", "the", "U.S", "COVID", "19"]
+        );
+        assert_eq!(
+            words(&UnicodeWordTokenizer, "Привет, мир. Это тест"),
+            vec!["Привет", "мир", "Это", "тест"]
+        );
+    }
+
+    #[test]
+    fn test_tokenizer_from_name() {
+        for name in ["alphanumeric", "whitespace", "regex", "unicode"] {
+            let tokenizer = tokenizer_from_name(name, None).unwrap();
+            assert!(!tokenizer.tokenize(TEXT).is_empty());
+        }
+        let tokenizer = tokenizer_from_name("regex", Some(r"[a-z]+")).unwrap();
+        assert_eq!(words(tokenizer.as_ref(), "ab1CD"), vec!["ab"]);
+        assert!(tokenizer_from_name("regex", Some("(")).is_err());
+        assert!(tokenizer_from_name("unknown", None).is_err());
+    }
+}
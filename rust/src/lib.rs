@@ -1,16 +1,31 @@
 pub mod api;
 pub mod aug;
+pub mod cli;
 pub mod doc;
 pub mod model;
 pub mod utils;
 
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "python")]
 #[pymodule]
 fn rust_fasttextaug(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<api::RustOCRApiClass>()?;
     m.add_class::<api::RustKeyboardApiClass>()?;
+    m.add_class::<api::RustLayoutSwitchApiClass>()?;
     m.add_class::<api::RustRandomCharApiClass>()?;
+    m.add_class::<api::RustHomoglyphApiClass>()?;
+    m.add_class::<api::RustConfusionApiClass>()?;
     m.add_class::<api::RustRandomWordApiClass>()?;
+    m.add_class::<api::RustSpellingApiClass>()?;
+    m.add_class::<api::RustSynonymApiClass>()?;
+    m.add_class::<api::RustAntonymApiClass>()?;
+    m.add_class::<api::RustSplitApiClass>()?;
+    m.add_class::<api::RustReservedApiClass>()?;
+    m.add_class::<api::RustTfIdfApiClass>()?;
+    m.add_class::<api::RustWordEmbsApiClass>()?;
+    m.add_class::<api::RustPipelineApiClass>()?;
+    m.add_function(wrap_pyfunction!(api::load_config, m)?)?;
     Ok(())
 }
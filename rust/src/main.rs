@@ -0,0 +1,94 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process;
+
+use clap::Parser;
+use fasttextaug::api::{AugmenterConfig, ConfigFormat};
+use fasttextaug::cli::{augment_inputs, CliOptions, InputFormat};
+
+/// Augment text, JSONL or CSV files with augmenter (or pipeline), described by JSON or TOML config
+#[derive(Parser)]
+#[command(name = "fasttextaug", version)]
+struct Args {
+    /// Config of augmenter, files with '.toml' suffix are read as TOML, others as JSON
+    #[arg(short, long)]
+    config: PathBuf,
+    /// Input files, stdin is read if none (or '-') is passed
+    inputs: Vec<PathBuf>,
+    /// Format of input and output
+    #[arg(short, long, default_value = "text", value_parser = ["text", "jsonl", "csv"])]
+    format: String,
+    /// JSONL field or CSV column (name or 0-based index) to augment
+    #[arg(long, default_value = "text")]
+    field: String,
+    /// CSV input has no header row
+    #[arg(long)]
+    no_header: bool,
+    /// Number of variants per input
+    #[arg(short, default_value_t = 1)]
+    n: usize,
+    /// Seed of augmenter, overrides seed from config
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Number of threads
+    #[arg(short, long, default_value_t = 1)]
+    threads: usize,
+    /// Number of inputs, augmented at once (output with fixed seed doesn't depend on it)
+    #[arg(long, default_value_t = 10_000)]
+    batch_size: usize,
+    /// Output file, stdout if not passed
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let format = match args.config.extension() {
+        Some(extension) if extension == "toml" => ConfigFormat::Toml,
+        _ => ConfigFormat::Json,
+    };
+    let config = std::fs::read_to_string(&args.config)
+        .map_err(|err| format!("{}: {}", args.config.display(), err))?;
+    let mut config = AugmenterConfig::parse(&config, format)?;
+    if let Some(seed) = args.seed {
+        config.set_seed(seed);
+    }
+    let api = config.build_api()?;
+
+    let mut readers: Vec<Box<dyn Read>> = Vec::with_capacity(args.inputs.len());
+    for path in &args.inputs {
+        if path.as_os_str() == "-" {
+            readers.push(Box::new(io::stdin()));
+        } else {
+            let file = File::open(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+            readers.push(Box::new(file));
+        }
+    }
+    if readers.is_empty() {
+        readers.push(Box::new(io::stdin()));
+    }
+    let mut writer: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+
+    let options = CliOptions {
+        format: InputFormat::from_name(&args.format).unwrap(),
+        field: args.field,
+        has_header: !args.no_header,
+        n: args.n,
+        n_threads: args.threads.max(1),
+        batch_size: args.batch_size.max(1),
+    };
+    augment_inputs(api.as_ref(), readers, &mut writer, &options)?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run(Args::parse()) {
+        eprintln!("fasttextaug: {}", err);
+        process::exit(1);
+    }
+}
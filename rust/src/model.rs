@@ -2,4 +2,4 @@ mod base;
 pub mod character;
 pub mod word;
 
-pub use base::{BaseModel, Mapping};
+pub use base::{BaseModel, EmptyModel, Mapping, WeightedMapping};
@@ -3,6 +3,9 @@ use std::collections::{HashMap, HashSet};
 /// Most common model type - Mapping from String to Vector of Strings
 pub type Mapping = HashMap<String, Vec<String>>;
 
+/// Mapping with probability (weight) of each value - {"l": {"1": 0.7, "I": 0.3}}
+pub type WeightedMapping = HashMap<String, HashMap<String, f32>>;
+
 /// Base models functionality
 pub trait BaseModel {
     /// Get mapping inside model
@@ -22,6 +25,13 @@ pub trait BaseModel {
         }
     }
 
+    /// Get weights of mapping values, if model has them
+    ///
+    /// Models without weights are sampled uniformly
+    fn get_weights(&self) -> Option<&WeightedMapping> {
+        None
+    }
+
     /// Check if key exists in model (If we can call predict method)
     fn key_exists(&self, data: &str) -> bool {
         if let Some(model) = self.get_mapping() {
@@ -40,17 +50,98 @@ pub trait BaseModel {
 
     /// Useful function, if someone pass duplicated keys/values to model
     ///  we can deduplicate it and use
+    ///
+    /// First occurrence of value is kept, so the order doesn't depend on HashSet
     fn deduplicate(mapping: Mapping) -> Mapping {
         let mut new_mapping = Mapping::with_capacity(mapping.capacity());
         for (key, value) in mapping {
-            let mut buffer = HashSet::with_capacity(value.capacity());
+            let mut seen = HashSet::with_capacity(value.capacity());
+            let mut buffer = Vec::with_capacity(value.len());
             for elem in value {
-                let _ = buffer.insert(elem);
+                if seen.insert(elem.clone()) {
+                    buffer.push(elem);
+                }
             }
-            new_mapping.insert(key, buffer.into_iter().collect());
+            new_mapping.insert(key, buffer);
         }
         new_mapping
     }
+
+    /// Make mapping symmetric, if 'l' looks like '1', so '1' looks like 'l'
+    fn reverse_mapping(mapping: &mut Mapping) {
+        let mut pairs_to_insert = Vec::with_capacity(mapping.capacity() * 2);
+        for (key, vec_value) in mapping.iter() {
+            for value in vec_value {
+                let is_in_hash = mapping.get(value);
+                match is_in_hash {
+                    None => {
+                        pairs_to_insert.push((value.clone(), key.clone()));
+                    }
+                    Some(vec_not_key) => {
+                        if !vec_not_key.contains(key) {
+                            pairs_to_insert.push((value.clone(), key.clone()));
+                        }
+                    }
+                }
+            }
+        }
+        // Sort pairs, so the order of reversed values doesn't depend on HashMap
+        pairs_to_insert.sort();
+
+        for (new_key, new_value) in pairs_to_insert.into_iter() {
+            if let Some(vec_of_val) = mapping.get_mut(&new_key) {
+                vec_of_val.push(new_value)
+            } else {
+                let mut new_vec_of_val = Vec::with_capacity(10);
+                new_vec_of_val.push(new_value);
+                mapping.insert(new_key.clone(), new_vec_of_val);
+            }
+        }
+    }
+
+    /// Make weighted mapping symmetric, reversed pair gets the same weight,
+    /// if it's not presented already
+    fn reverse_weighted_mapping(mapping: &mut WeightedMapping) {
+        let mut pairs_to_insert = Vec::with_capacity(mapping.capacity() * 2);
+        for (key, values) in mapping.iter() {
+            for (value, weight) in values {
+                let reversed_exists = mapping
+                    .get(value)
+                    .is_some_and(|reversed| reversed.contains_key(key));
+                if !reversed_exists {
+                    pairs_to_insert.push((value.clone(), key.clone(), *weight));
+                }
+            }
+        }
+
+        for (new_key, new_value, weight) in pairs_to_insert.into_iter() {
+            mapping
+                .entry(new_key)
+                .or_default()
+                .insert(new_value, weight);
+        }
+    }
+
+    /// Mapping from weighted one, values are sorted, so the order doesn't depend on HashMap
+    fn weighted_to_mapping(weighted: &WeightedMapping) -> Mapping {
+        let mut mapping = Mapping::with_capacity(weighted.len());
+        for (key, values) in weighted {
+            let mut vec_values: Vec<String> = values.keys().cloned().collect();
+            vec_values.sort();
+            mapping.insert(key.clone(), vec_values);
+        }
+        mapping
+    }
+}
+
+/// Model for augmentors, that don't need any data to perform augmentation (e.g. split words)
+#[derive(Clone)]
+pub struct EmptyModel;
+
+impl BaseModel for EmptyModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -80,6 +171,10 @@ mod tests {
         ]);
         let deduplicated_hash = MockModel::deduplicate(input_hash);
         assert_eq!(deduplicated_hash.get("A").unwrap().len(), 2);
+        assert_eq!(
+            deduplicated_hash["A"],
+            vec![String::from("a"), String::from("c")]
+        );
         assert_eq!(deduplicated_hash.get("B").unwrap().len(), 1);
     }
 
@@ -138,5 +233,26 @@ mod tests {
         );
         assert_eq!(*model.predict("B").unwrap(), vec![String::from("f")]);
         assert_eq!(model.predict("C"), None);
+        assert_eq!(model.get_weights(), None);
+    }
+
+    #[test]
+    fn test_reverse_weighted_mapping() {
+        let mut weighted: WeightedMapping = HashMap::from([
+            (
+                String::from("l"),
+                HashMap::from([(String::from("1"), 0.7), (String::from("I"), 0.3)]),
+            ),
+            (String::from("I"), HashMap::from([(String::from("l"), 0.5)])),
+        ]);
+        MockModel::reverse_weighted_mapping(&mut weighted);
+        assert_eq!(weighted.len(), 3);
+        assert_eq!(weighted["1"], HashMap::from([(String::from("l"), 0.7)]));
+        // Existing pair keeps its own weight
+        assert_eq!(weighted["I"], HashMap::from([(String::from("l"), 0.5)]));
+
+        let mapping = MockModel::weighted_to_mapping(&weighted);
+        assert_eq!(mapping["l"], vec![String::from("1"), String::from("I")]);
+        assert_eq!(mapping["1"], vec![String::from("l")]);
     }
 }
@@ -1,4 +1,8 @@
+mod confusion;
+mod homoglyph;
 mod keyboard;
+mod keyboard_layout;
+mod layout_switch;
 mod ocr;
 mod random;
 
@@ -6,6 +10,10 @@ use super::BaseModel;
 
 pub trait CharacterModel: BaseModel {}
 
+pub use confusion::ConfusionModel;
+pub use homoglyph::HomoglyphModel;
 pub use keyboard::KeyboardModel;
+pub use keyboard_layout::{KeyboardLayout, LayoutRow};
+pub use layout_switch::LayoutSwitchModel;
 pub use ocr::OcrModel;
 pub use random::RandomCharModel;
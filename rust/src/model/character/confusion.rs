@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::super::base::{BaseModel, Mapping, WeightedMapping};
+use super::CharacterModel;
+use crate::utils;
+
+/// One step of character-level Levenshtein alignment
+#[derive(Debug, PartialEq)]
+enum Edit {
+    Match(char),
+    Substitute(char, char),
+    Insert(char),
+    Delete(char),
+}
+
+/// Confusion model - emulates errors of your own OCR/ASR system, learned from parallel clean/noisy text
+///
+/// Value of mapping may be empty (char is deleted)
+/// or longer than one char (noisy chars were inserted after clean one)
+pub struct ConfusionModel {
+    /// Path on your system to load json-file model (weighted mapping, created by `save`)
+    model_path: String,
+    /// Mapping
+    model: Option<Mapping>,
+    /// Probability of each confusion among all confusions of the char
+    weights: Option<WeightedMapping>,
+}
+
+impl ConfusionModel {
+    pub fn new(model_path: String) -> Self {
+        Self {
+            model_path,
+            model: None,
+            weights: None,
+        }
+    }
+
+    pub fn new_from_weighted_mapping(weighted: WeightedMapping) -> Self {
+        ConfusionModel {
+            model_path: String::from("internal mapping"),
+            model: Some(Self::weighted_to_mapping(&weighted)),
+            weights: Some(weighted),
+        }
+    }
+
+    /// Levenshtein alignment of two char sequences with unit costs
+    ///
+    /// If there are several alignments with the same cost, match and substitution are preferred
+    fn align(clean: &[char], noisy: &[char]) -> Vec<Edit> {
+        let (n, m) = (clean.len(), noisy.len());
+        let mut dist = vec![vec![0usize; m + 1]; n + 1];
+        for (i, row) in dist.iter_mut().enumerate() {
+            row[0] = i;
+        }
+        for (j, cell) in dist[0].iter_mut().enumerate() {
+            *cell = j;
+        }
+        for i in 1..=n {
+            for j in 1..=m {
+                let sub_cost = usize::from(clean[i - 1] != noisy[j - 1]);
+                dist[i][j] = (dist[i - 1][j - 1] + sub_cost)
+                    .min(dist[i - 1][j] + 1)
+                    .min(dist[i][j - 1] + 1);
+            }
+        }
+
+        let mut edits = Vec::with_capacity(n.max(m));
+        let (mut i, mut j) = (n, m);
+        while i > 0 || j > 0 {
+            if i > 0 && j > 0 {
+                let sub_cost = usize::from(clean[i - 1] != noisy[j - 1]);
+                if dist[i][j] == dist[i - 1][j - 1] + sub_cost {
+                    match sub_cost {
+                        0 => edits.push(Edit::Match(clean[i - 1])),
+                        _ => edits.push(Edit::Substitute(clean[i - 1], noisy[j - 1])),
+                    }
+                    i -= 1;
+                    j -= 1;
+                    continue;
+                }
+            }
+            if i > 0 && dist[i][j] == dist[i - 1][j] + 1 {
+                edits.push(Edit::Delete(clean[i - 1]));
+                i -= 1;
+            } else {
+                edits.push(Edit::Insert(noisy[j - 1]));
+                j -= 1;
+            }
+        }
+        edits.reverse();
+        edits
+    }
+
+    /// Align pair and count, what each clean char turned into
+    ///
+    /// Whitespace chars are never augmented, so inserted chars are attached to the previous
+    /// non-whitespace clean char, or to the next one after whitespace and at the start of text
+    fn count_confusions(
+        clean: &str,
+        noisy: &str,
+        counts: &mut HashMap<String, HashMap<String, usize>>,
+    ) {
+        let clean_chars: Vec<char> = clean.chars().collect();
+        let noisy_chars: Vec<char> = noisy.chars().collect();
+        let mut outputs: Vec<(char, String)> = Vec::with_capacity(clean_chars.len());
+        // Inserted chars, that wait for the next non-whitespace clean char
+        let mut pending = String::new();
+        for edit in Self::align(&clean_chars, &noisy_chars) {
+            let (ch, output) = match edit {
+                Edit::Match(ch) => (ch, ch.to_string()),
+                Edit::Substitute(ch, noisy_ch) => (ch, noisy_ch.to_string()),
+                Edit::Delete(ch) => (ch, String::new()),
+                Edit::Insert(noisy_ch) => {
+                    match outputs.last_mut() {
+                        Some((ch, output)) if !ch.is_whitespace() => output.push(noisy_ch),
+                        _ => pending.push(noisy_ch),
+                    }
+                    continue;
+                }
+            };
+            match ch.is_whitespace() {
+                true => outputs.push((ch, output)),
+                false => outputs.push((ch, std::mem::take(&mut pending) + &output)),
+            }
+        }
+        // Chars, inserted after trailing whitespace
+        if let Some((_, output)) = outputs.iter_mut().rev().find(|(ch, _)| !ch.is_whitespace()) {
+            output.push_str(&pending);
+        }
+
+        for (ch, output) in outputs {
+            if ch.is_whitespace() || output == ch.to_string() {
+                continue;
+            }
+            *counts
+                .entry(ch.to_string())
+                .or_default()
+                .entry(output)
+                .or_insert(0) += 1;
+        }
+    }
+
+    /// Train model on pairs of (clean, noisy) text
+    ///
+    /// Confusions, met less than min_count times, are dropped.
+    /// Weight of confusion is its share among all confusions of the char
+    pub fn train(pairs: &[(String, String)], min_count: usize) -> Self {
+        let mut counts: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        for (clean, noisy) in pairs {
+            Self::count_confusions(clean, noisy, &mut counts);
+        }
+
+        let mut weighted = WeightedMapping::with_capacity(counts.len());
+        for (key, confusions) in counts {
+            let confusions: Vec<(String, usize)> = confusions
+                .into_iter()
+                .filter(|(_, count)| *count >= min_count)
+                .collect();
+            let total: usize = confusions.iter().map(|(_, count)| count).sum();
+            if total == 0 {
+                continue;
+            }
+            let values = confusions
+                .into_iter()
+                .map(|(value, count)| (value, count as f32 / total as f32))
+                .collect();
+            weighted.insert(key, values);
+        }
+        Self::new_from_weighted_mapping(weighted)
+    }
+
+    /// Save model to json-file in weighted format `{"l": {"1": 0.7, "I": 0.3}}`
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut json_map = Map::new();
+        if let Some(weighted) = &self.weights {
+            for (key, values) in weighted {
+                let mut json_values = Map::with_capacity(values.len());
+                for (value, weight) in values {
+                    json_values.insert(value.clone(), Value::from(*weight));
+                }
+                json_map.insert(key.clone(), Value::Object(json_values));
+            }
+        }
+        fs::write(path, serde_json::to_string_pretty(&json_map)?)?;
+        Ok(())
+    }
+
+    /// Lazy-file read, before this method executed -> Model = None
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.model.is_some() {
+            return Ok(());
+        }
+        let model_path = Path::new(&self.model_path);
+        let weighted_from_file = utils::read_weighted_mapping(model_path, Some(100), Some(10))
+            .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        self.model = Some(Self::weighted_to_mapping(&weighted_from_file));
+        self.weights = Some(weighted_from_file);
+        Ok(())
+    }
+}
+
+impl BaseModel for ConfusionModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        self.model.as_ref()
+    }
+
+    fn get_weights(&self) -> Option<&WeightedMapping> {
+        self.weights.as_ref()
+    }
+}
+
+impl CharacterModel for ConfusionModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Vec<char> {
+        input.chars().collect()
+    }
+
+    #[test]
+    fn test_align() {
+        let edits = ConfusionModel::align(&chars("hello"), &chars("he1lo"));
+        assert_eq!(
+            edits,
+            vec![
+                Edit::Match('h'),
+                Edit::Match('e'),
+                Edit::Substitute('l', '1'),
+                Edit::Match('l'),
+                Edit::Match('o'),
+            ]
+        );
+
+        let edits = ConfusionModel::align(&chars("modern"), &chars("rnodrn"));
+        assert_eq!(edits.len(), 7);
+        assert!(edits.contains(&Edit::Delete('e')));
+
+        assert_eq!(ConfusionModel::align(&[], &chars("ab")).len(), 2);
+        assert_eq!(ConfusionModel::align(&chars("ab"), &[]).len(), 2);
+    }
+
+    #[test]
+    fn test_train() {
+        let pairs = vec![
+            (String::from("lily"), String::from("1ily")),
+            (String::from("fill"), String::from("fiIl")),
+            (String::from("all"), String::from("a1l")),
+            (String::from("cat"), String::from("ct")),
+            (String::from("go"), String::from("go!")),
+            (String::from("Мир"), String::from("Мир")),
+        ];
+        let model = ConfusionModel::train(&pairs, 1);
+        let weights = model.get_weights().unwrap();
+        assert_eq!(weights.len(), 3);
+        assert!((weights["l"]["1"] - 2.0 / 3.0).abs() < 1e-6);
+        assert!((weights["l"]["I"] - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(weights["a"][""], 1.0);
+        assert_eq!(weights["o"]["o!"], 1.0);
+        assert_eq!(
+            model.predict("l"),
+            Some(&vec![String::from("1"), String::from("I")])
+        );
+        assert!(!model.key_exists("М"));
+
+        let model = ConfusionModel::train(&pairs, 2);
+        assert_eq!(model.get_weights().unwrap().len(), 1);
+        assert_eq!(model.predict("l"), Some(&vec![String::from("1")]));
+    }
+
+    #[test]
+    fn test_train_leading_insert() {
+        let pairs = vec![(String::from("ok ok"), String::from("'ok 0k"))];
+        let model = ConfusionModel::train(&pairs, 1);
+        let weights = model.get_weights().unwrap();
+        assert_eq!(weights["o"]["'o"], 0.5);
+        assert_eq!(weights["o"]["0"], 0.5);
+    }
+
+    #[test]
+    fn test_train_insert_near_whitespace() {
+        let pairs = vec![
+            (String::from("ab cd"), String::from("ab xcd")),
+            (String::from("ab cd"), String::from("ab! cd")),
+            (String::from("ef "), String::from("ef ?")),
+        ];
+        let model = ConfusionModel::train(&pairs, 1);
+        let weights = model.get_weights().unwrap();
+        assert_eq!(weights["c"]["xc"], 1.0);
+        assert_eq!(weights["b"]["b!"], 1.0);
+        assert_eq!(weights["f"]["f?"], 1.0);
+        assert!(!model.key_exists(" "));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let pairs = vec![(String::from("lol"), String::from("1o"))];
+        let model = ConfusionModel::train(&pairs, 1);
+        let path = std::env::temp_dir().join(format!(
+            "fasttextaug_test_confusion_{}.json",
+            std::process::id()
+        ));
+        model.save(&path).unwrap();
+
+        let mut loaded = ConfusionModel::new(String::from(path.to_str().unwrap()));
+        assert_eq!(loaded.get_mapping(), None);
+        loaded.load_model().unwrap();
+        assert_eq!(loaded.get_weights(), model.get_weights());
+        assert_eq!(loaded.predict("l"), model.predict("l"));
+        fs::remove_file(path).unwrap();
+    }
+}
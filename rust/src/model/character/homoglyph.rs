@@ -0,0 +1,94 @@
+use super::super::base::{BaseModel, Mapping};
+use super::CharacterModel;
+use crate::utils;
+use std::error::Error;
+use std::path::Path;
+
+/// Homoglyph model - replace char with visually confusable unicode char, like Latin 'a' -> Cyrillic 'а'
+///
+/// Unlike OcrModel, mapping is not reversed: direction of replacement matters
+pub struct HomoglyphModel {
+    /// Path on your system to load json-file model
+    model_path: String,
+    /// Mapping
+    model: Option<Mapping>,
+}
+
+impl HomoglyphModel {
+    pub fn new(model_path: String) -> Self {
+        Self {
+            model_path,
+            model: None,
+        }
+    }
+
+    pub fn new_from_mapping(mapping: Mapping) -> Self {
+        let deduplicated_mapping = Self::deduplicate(mapping);
+        HomoglyphModel {
+            model_path: String::from("internal mapping"),
+            model: Some(deduplicated_mapping),
+        }
+    }
+
+    /// Lazy-file read, before this method executed -> Model = None
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.model.is_some() {
+            return Ok(());
+        }
+        let model_path = Path::new(&self.model_path);
+        let mapping_from_file = utils::read_mapping(model_path, Some(100), Some(5))
+            .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        self.model = Some(Self::deduplicate(mapping_from_file));
+        Ok(())
+    }
+}
+
+impl BaseModel for HomoglyphModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        if let Some(model) = &self.model {
+            return Some(model);
+        }
+        None
+    }
+}
+
+impl CharacterModel for HomoglyphModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transform_to_set;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_load_model() {
+        let mut model = HomoglyphModel::new(String::from("test_res/homoglyph_en.json"));
+        assert_eq!(model.get_mapping(), None);
+
+        model.load_model().unwrap();
+        assert_eq!(model.predict("a"), Some(&vec![String::from("а")]));
+        assert_eq!(model.predict("A"), Some(&vec![String::from("А")]));
+        assert_eq!(model.predict("o").unwrap().len(), 2);
+        // Mapping is not reversed
+        assert!(!model.key_exists("а"));
+        assert!(!model.key_exists("b"));
+    }
+
+    #[test]
+    fn test_create_from_mapping() {
+        let mapping = HashMap::from([(
+            String::from("o"),
+            vec![String::from("о"), String::from("о"), String::from("ο")],
+        )]);
+        let model = HomoglyphModel::new_from_mapping(mapping);
+        let expected = HashMap::from([(
+            String::from("o"),
+            vec![String::from("о"), String::from("ο")],
+        )]);
+        assert_eq!(
+            transform_to_set(model.get_mapping().unwrap()),
+            transform_to_set(&expected)
+        );
+        assert_eq!(model.model_path, String::from("internal mapping"));
+    }
+}
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+use std::error::Error;
 use std::path::Path;
 
-use super::super::{BaseModel, Mapping};
-use super::CharacterModel;
+use super::super::{BaseModel, Mapping, WeightedMapping};
+use super::{CharacterModel, KeyboardLayout};
 use crate::utils;
 
 /// Keyboard model - emulates user typos
@@ -16,6 +18,8 @@ pub struct KeyboardModel {
     model_path: String,
     /// Mapping
     model: Option<Mapping>,
+    /// Weights of mapping values, used to draw more probable typos more often
+    weights: Option<WeightedMapping>,
 }
 
 impl KeyboardModel {
@@ -47,46 +51,86 @@ impl KeyboardModel {
             upper_case,
             model_path,
             model: None,
+            weights: None,
         };
         model
     }
 
+    /// Generate model from geometric layout instead of json-file
+    ///
+    /// Neighbours are keys within max_distance (in key widths), see `KeyboardLayout::neighbours`
+    pub fn from_layout(
+        allow_special_char: bool,
+        allow_numeric: bool,
+        upper_case: bool,
+        layout: &KeyboardLayout,
+        max_distance: f32,
+        distance_decay: Option<f32>,
+    ) -> Self {
+        let mut model = Self::new(
+            allow_special_char,
+            allow_numeric,
+            upper_case,
+            String::from("internal layout"),
+        );
+        let keyboard_mapping = model.build_mapping(layout.neighbours(max_distance, distance_decay));
+        model.model = Some(Self::weighted_to_mapping(&keyboard_mapping));
+        model.weights = Some(keyboard_mapping);
+        model
+    }
+
     /// Lazy-file read, before this method executed -> Model = None
-    pub fn load_model(&mut self) {
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
         if let Some(_) = self.model {
-            return;
+            return Ok(());
         }
 
         let model_path = Path::new(&self.model_path);
-        let mapping_from_file = utils::read_mapping(model_path, Some(100), Some(15)).unwrap();
-        let mut keyboard_mapping = Mapping::with_capacity(mapping_from_file.capacity());
+        let mapping_from_file = utils::read_weighted_mapping(model_path, Some(100), Some(15))
+            .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        let keyboard_mapping = self.build_mapping(mapping_from_file);
+        self.model = Some(Self::weighted_to_mapping(&keyboard_mapping));
+        self.weights = Some(keyboard_mapping);
+        Ok(())
+    }
+
+    /// Filter raw neighbours by conditions and add uppercase variants, if it's allowed
+    fn build_mapping(&self, raw_mapping: WeightedMapping) -> WeightedMapping {
+        let mut keyboard_mapping = WeightedMapping::with_capacity(raw_mapping.capacity());
 
-        for (key, arr) in mapping_from_file.into_iter() {
+        for (key, values) in raw_mapping.into_iter() {
             if self.check_conditions(&key) {
-                let mut arr_to_key = Vec::with_capacity(arr.capacity() * 2);
-                let mut arr_to_caps_key = Vec::with_capacity(arr.capacity() * 2);
+                let mut values_to_key = HashMap::with_capacity(values.capacity() * 2);
+                let mut values_to_caps_key = HashMap::with_capacity(values.capacity() * 2);
                 let upper_diff = self.upper_case & (key != key.to_uppercase());
-                for value in arr {
+                for (value, weight) in values {
                     if self.check_conditions(&value) {
                         if upper_diff {
-                            arr_to_caps_key.push(value.to_uppercase());
-                            arr_to_caps_key.push(value.clone());
+                            values_to_caps_key.insert(value.to_uppercase(), weight);
+                            values_to_caps_key.insert(value.clone(), weight);
                         }
                         if self.upper_case {
-                            arr_to_key.push(value.to_uppercase());
+                            values_to_key.insert(value.to_uppercase(), weight);
                         }
-                        arr_to_key.push(value);
+                        values_to_key.insert(value, weight);
                     }
                 }
-                if arr_to_caps_key.len() > 0 {
-                    keyboard_mapping.insert(key.to_uppercase(), arr_to_caps_key);
+                // Layouts have shift layer, so uppercase key may be met twice - merge its values
+                if !values_to_caps_key.is_empty() {
+                    keyboard_mapping
+                        .entry(key.to_uppercase())
+                        .or_default()
+                        .extend(values_to_caps_key);
                 }
-                if arr_to_key.len() > 0 {
-                    keyboard_mapping.insert(key, arr_to_key);
+                if !values_to_key.is_empty() {
+                    keyboard_mapping
+                        .entry(key)
+                        .or_default()
+                        .extend(values_to_key);
                 }
             }
         }
-        self.model = Some(Self::deduplicate(keyboard_mapping));
+        keyboard_mapping
     }
 }
 
@@ -97,6 +141,10 @@ impl BaseModel for KeyboardModel {
         }
         return None;
     }
+
+    fn get_weights(&self) -> Option<&WeightedMapping> {
+        self.weights.as_ref()
+    }
 }
 
 impl CharacterModel for KeyboardModel {}
@@ -105,7 +153,7 @@ impl CharacterModel for KeyboardModel {}
 mod tests {
     use super::*;
     use crate::utils::transform_to_set;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn test_check_conditions() {
@@ -115,6 +163,7 @@ mod tests {
             upper_case: true,
             model_path: String::from(""),
             model: None,
+            weights: None,
         };
         assert!(allow_all.check_conditions("й"));
         assert!(allow_all.check_conditions("7"));
@@ -126,6 +175,7 @@ mod tests {
             upper_case: true,
             model_path: String::from(""),
             model: None,
+            weights: None,
         };
         assert!(forbid_special_chars.check_conditions("L"));
         assert!(forbid_special_chars.check_conditions("4"));
@@ -137,6 +187,7 @@ mod tests {
             upper_case: true,
             model_path: String::from(""),
             model: None,
+            weights: None,
         };
         assert!(forbid_digits.check_conditions("f"));
         assert!(!forbid_digits.check_conditions("4"));
@@ -148,6 +199,7 @@ mod tests {
             upper_case: true,
             model_path: String::from(""),
             model: None,
+            weights: None,
         };
         assert!(forbid_all.check_conditions("Б"));
         assert!(!forbid_all.check_conditions("9"));
@@ -162,8 +214,9 @@ mod tests {
             upper_case: true,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        allow_all_caps.load_model();
+        allow_all_caps.load_model().unwrap();
         let allow_all_caps_set = transform_to_set(allow_all_caps.get_mapping().unwrap());
         let expected_allow_all_caps = HashMap::from([
             (
@@ -217,8 +270,9 @@ mod tests {
             upper_case: false,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        allow_all.load_model();
+        allow_all.load_model().unwrap();
         let allow_all_set = transform_to_set(allow_all.get_mapping().unwrap());
         let expected_allow_all = HashMap::from([
             (
@@ -245,8 +299,9 @@ mod tests {
             upper_case: true,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        forbid_spec_caps.load_model();
+        forbid_spec_caps.load_model().unwrap();
         let forbid_spec_caps_set = transform_to_set(forbid_spec_caps.get_mapping().unwrap());
         let expected_forbid_spec_caps = HashMap::from([
             (
@@ -276,8 +331,9 @@ mod tests {
             upper_case: false,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        forbid_spec.load_model();
+        forbid_spec.load_model().unwrap();
         let forbid_spec_set = transform_to_set(forbid_spec.get_mapping().unwrap());
         let expected_forbid_spec = HashMap::from([
             (
@@ -300,8 +356,9 @@ mod tests {
             upper_case: true,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        forbid_num_caps.load_model();
+        forbid_num_caps.load_model().unwrap();
         let forbid_num_caps_set = transform_to_set(forbid_num_caps.get_mapping().unwrap());
         let expected_forbid_num_caps = HashMap::from([
             (
@@ -331,8 +388,9 @@ mod tests {
             upper_case: false,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        forbid_num.load_model();
+        forbid_num.load_model().unwrap();
         let forbid_num_set = transform_to_set(forbid_num.get_mapping().unwrap());
         let expected_forbid_num = HashMap::from([
             (
@@ -355,8 +413,9 @@ mod tests {
             upper_case: true,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        forbid_all_caps.load_model();
+        forbid_all_caps.load_model().unwrap();
         let forbid_all_caps_set = transform_to_set(forbid_all_caps.get_mapping().unwrap());
         let expected_forbid_all_caps = HashMap::from([
             (
@@ -382,8 +441,9 @@ mod tests {
             upper_case: false,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
-        forbid_all.load_model();
+        forbid_all.load_model().unwrap();
         let forbid_all_set = transform_to_set(forbid_all.get_mapping().unwrap());
         let expected_forbid_all = HashMap::from([(String::from("а"), vec![String::from("б")])]);
         assert_eq!(forbid_all_set, transform_to_set(&expected_forbid_all));
@@ -397,12 +457,59 @@ mod tests {
             upper_case: false,
             model_path: String::from("test_res/small_keyboard.json"),
             model: None,
+            weights: None,
         };
         assert_eq!(key_model.get_mapping(), None);
-        key_model.load_model();
+        key_model.load_model().unwrap();
         let expected_all = HashMap::from([(String::from("а"), vec![String::from("б")])]);
         assert_eq!(*key_model.get_mapping().unwrap(), expected_all);
-        key_model.load_model();
+        key_model.load_model().unwrap();
         assert_eq!(*key_model.get_mapping().unwrap(), expected_all);
     }
+
+    #[test]
+    fn test_load_weighted_model() {
+        let mut model = KeyboardModel::new(
+            true,
+            true,
+            true,
+            String::from("test_res/weighted_mapping.json"),
+        );
+        model.load_model().unwrap();
+        let weights = model.get_weights().unwrap();
+        assert_eq!(
+            weights["l"],
+            HashMap::from([(String::from("1"), 0.7), (String::from("I"), 0.3)])
+        );
+        assert_eq!(weights["L"], weights["l"]);
+        assert_eq!(weights["0"]["O"], 1.0);
+        assert_eq!(
+            model.predict("l"),
+            Some(&vec![String::from("1"), String::from("I")])
+        );
+        assert!(!model.key_exists("x"));
+    }
+
+    #[test]
+    fn test_from_layout() {
+        let layout = KeyboardLayout::from_name("qwerty").unwrap();
+        let model = KeyboardModel::from_layout(false, false, true, &layout, 1.5, Some(1.0));
+        let mapping_set = transform_to_set(model.get_mapping().unwrap());
+        assert_eq!(
+            mapping_set["q"],
+            HashSet::from([
+                String::from("w"),
+                String::from("W"),
+                String::from("a"),
+                String::from("A")
+            ])
+        );
+        // Shift layer and uppercase variants of lowercase key are merged
+        assert_eq!(mapping_set["Q"], mapping_set["q"]);
+        assert!(!model.key_exists("1"));
+        assert!(!model.key_exists("!"));
+        let weights = model.get_weights().unwrap();
+        assert!(weights["g"]["f"] > weights["g"]["y"]);
+        assert_eq!(weights["g"]["f"], weights["g"]["F"]);
+    }
 }
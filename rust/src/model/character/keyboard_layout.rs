@@ -0,0 +1,267 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::super::{Mapping, WeightedMapping};
+
+/// Row of keys on physical keyboard
+pub struct LayoutRow {
+    /// Shift of the first key from the left edge of keyboard, in key widths
+    offset: f32,
+    /// Chars, typed without shift
+    keys: Vec<char>,
+    /// Chars, typed with shift (same position as in keys). Shorter than keys, if some keys have no shift layer
+    shift_keys: Vec<char>,
+}
+
+impl LayoutRow {
+    pub fn new(offset: f32, keys: &str, shift_keys: &str) -> Self {
+        Self {
+            offset,
+            keys: keys.chars().collect(),
+            shift_keys: shift_keys.chars().collect(),
+        }
+    }
+}
+
+/// Geometric keyboard layout - rows of keys with their offsets
+///
+/// Every key is considered to be 1x1, so position of key is (row offset + column, row)
+pub struct KeyboardLayout {
+    rows: Vec<LayoutRow>,
+}
+
+impl KeyboardLayout {
+    pub fn new(rows: Vec<LayoutRow>) -> Self {
+        Self { rows }
+    }
+
+    /// Built-in layouts: 'qwerty', 'qwertz', 'azerty', 'dvorak', 'jcuken' ('йцукен')
+    pub fn from_name(name: &str) -> Option<Self> {
+        let rows = match name {
+            "qwerty" => vec![
+                LayoutRow::new(0.0, "`1234567890-=", "~!@#$%^&*()_+"),
+                LayoutRow::new(1.5, "qwertyuiop[]\\", "QWERTYUIOP{}|"),
+                LayoutRow::new(1.75, "asdfghjkl;'", "ASDFGHJKL:\""),
+                LayoutRow::new(2.25, "zxcvbnm,./", "ZXCVBNM<>?"),
+            ],
+            "qwertz" => vec![
+                LayoutRow::new(0.0, "^1234567890ß´", "°!\"§$%&/()=?`"),
+                LayoutRow::new(1.5, "qwertzuiopü+", "QWERTZUIOPÜ*"),
+                LayoutRow::new(1.75, "asdfghjklöä#", "ASDFGHJKLÖÄ'"),
+                LayoutRow::new(1.25, "<yxcvbnm,.-", ">YXCVBNM;:_"),
+            ],
+            "azerty" => vec![
+                LayoutRow::new(0.0, "²&é\"'(-è_çà)=", "²1234567890°+"),
+                LayoutRow::new(1.5, "azertyuiop^$", "AZERTYUIOP¨£"),
+                LayoutRow::new(1.75, "qsdfghjklmù*", "QSDFGHJKLM%µ"),
+                LayoutRow::new(1.25, "<wxcvbn,;:!", ">WXCVBN?./§"),
+            ],
+            "dvorak" => vec![
+                LayoutRow::new(0.0, "`1234567890[]", "~!@#$%^&*(){}"),
+                LayoutRow::new(1.5, "',.pyfgcrl/=\\", "\"<>PYFGCRL?+|"),
+                LayoutRow::new(1.75, "aoeuidhtns-", "AOEUIDHTNS_"),
+                LayoutRow::new(2.25, ";qjkxbmwvz", ":QJKXBMWVZ"),
+            ],
+            "jcuken" | "йцукен" => vec![
+                LayoutRow::new(0.0, "ё1234567890-=", "Ё!\"№;%:?*()_+"),
+                LayoutRow::new(1.5, "йцукенгшщзхъ\\", "ЙЦУКЕНГШЩЗХЪ/"),
+                LayoutRow::new(1.75, "фывапролджэ", "ФЫВАПРОЛДЖЭ"),
+                LayoutRow::new(2.25, "ячсмитьбю.", "ЯЧСМИТЬБЮ,"),
+            ],
+            _ => return None,
+        };
+        Some(Self::new(rows))
+    }
+
+    /// Built-in layout by its name or custom layout from json-file by its path
+    pub fn from_name_or_file(layout: &str) -> Result<Self, Box<dyn Error>> {
+        match Self::from_name(layout) {
+            Some(builtin) => Ok(builtin),
+            None => Self::from_file(Path::new(layout)),
+        }
+    }
+
+    /// Read custom layout from json-file
+    ///
+    /// Expected format: `{"rows": [{"offset": 0.0, "keys": "`123", "shift_keys": "~!@#"}, ...]}`,
+    /// "offset" and "shift_keys" are optional
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let file_content = fs::read_to_string(path)?;
+        let json_map: Map<String, Value> = serde_json::from_str(&file_content)?;
+        let json_rows = match json_map.get("rows") {
+            Some(Value::Array(json_rows)) => json_rows,
+            _ => return Err("layout must contain 'rows' array".into()),
+        };
+        let mut rows = Vec::with_capacity(json_rows.len());
+        for json_row in json_rows {
+            let offset = json_row
+                .get("offset")
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0);
+            let keys = match json_row.get("keys").and_then(Value::as_str) {
+                Some(keys) => keys,
+                None => return Err("every layout row must contain 'keys' string".into()),
+            };
+            let shift_keys = json_row
+                .get("shift_keys")
+                .and_then(Value::as_str)
+                .unwrap_or("");
+            rows.push(LayoutRow::new(offset as f32, keys, shift_keys));
+        }
+        Ok(Self::new(rows))
+    }
+
+    /// Char of this layout -> char on the same key of `other` layout, for both layers
+    ///
+    /// Chars, that are the same in both layouts (like digits), are skipped
+    pub fn switch_mapping(&self, other: &KeyboardLayout) -> Mapping {
+        let mut mapping = Mapping::new();
+        for (row, other_row) in self.rows.iter().zip(other.rows.iter()) {
+            let layers = [
+                (&row.keys, &other_row.keys),
+                (&row.shift_keys, &other_row.shift_keys),
+            ];
+            for (keys, other_keys) in layers {
+                for (key, other_key) in keys.iter().zip(other_keys.iter()) {
+                    if key != other_key {
+                        mapping
+                            .entry(key.to_string())
+                            .or_insert_with(|| vec![other_key.to_string()]);
+                    }
+                }
+            }
+        }
+        mapping
+    }
+
+    /// Positions of all keys: (key, shift key, x, y)
+    fn key_positions(&self) -> Vec<(char, Option<char>, f32, f32)> {
+        let mut positions = Vec::new();
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            for (col_idx, key) in row.keys.iter().enumerate() {
+                let shift_key = row.shift_keys.get(col_idx).copied();
+                let x = row.offset + col_idx as f32;
+                positions.push((*key, shift_key, x, row_idx as f32));
+            }
+        }
+        positions
+    }
+
+    /// Neighbours of every key (and its shift layer) within max_distance
+    ///
+    /// If distance_decay is passed, neighbour gets weight `exp(-distance_decay * distance)`,
+    /// so closer keys are drawn more often. Otherwise all neighbours are equally probable
+    pub fn neighbours(&self, max_distance: f32, distance_decay: Option<f32>) -> WeightedMapping {
+        let positions = self.key_positions();
+        let mut mapping = WeightedMapping::with_capacity(positions.len() * 2);
+        for (key, shift_key, x, y) in positions.iter() {
+            for (other_key, other_shift_key, other_x, other_y) in positions.iter() {
+                let distance = f32::hypot(x - other_x, y - other_y);
+                if distance == 0.0 || distance > max_distance {
+                    continue;
+                }
+                let weight = match distance_decay {
+                    Some(decay) => f32::exp(-decay * distance),
+                    None => 1.0,
+                };
+                let layers = [
+                    (Some(*key), Some(*other_key)),
+                    (*shift_key, *other_shift_key),
+                ];
+                for (from, to) in layers {
+                    if let (Some(from), Some(to)) = (from, to) {
+                        if from != to {
+                            mapping
+                                .entry(from.to_string())
+                                .or_default()
+                                .insert(to.to_string(), weight);
+                        }
+                    }
+                }
+            }
+        }
+        mapping
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn neighbour_set(mapping: &WeightedMapping, key: &str) -> HashSet<String> {
+        mapping[key].keys().cloned().collect()
+    }
+
+    fn strings(values: &[&str]) -> HashSet<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_builtin_layouts() {
+        for name in ["qwerty", "qwertz", "azerty", "dvorak", "jcuken", "йцукен"] {
+            let layout = KeyboardLayout::from_name(name).unwrap();
+            for row in layout.rows.iter() {
+                assert_eq!(row.keys.len(), row.shift_keys.len());
+            }
+        }
+        assert!(KeyboardLayout::from_name("colemak").is_none());
+    }
+
+    #[test]
+    fn test_qwerty_neighbours() {
+        let layout = KeyboardLayout::from_name("qwerty").unwrap();
+        let mapping = layout.neighbours(1.5, None);
+        assert_eq!(
+            neighbour_set(&mapping, "g"),
+            strings(&["f", "h", "t", "y", "v", "b"])
+        );
+        assert_eq!(neighbour_set(&mapping, "q"), strings(&["1", "2", "w", "a"]));
+        assert_eq!(neighbour_set(&mapping, "Q"), strings(&["!", "@", "W", "A"]));
+        assert_eq!(mapping["g"]["f"], 1.0);
+    }
+
+    #[test]
+    fn test_jcuken_neighbours_with_decay() {
+        let layout = KeyboardLayout::from_name("йцукен").unwrap();
+        let mapping = layout.neighbours(1.5, Some(1.0));
+        assert_eq!(
+            neighbour_set(&mapping, "п"),
+            strings(&["а", "р", "е", "н", "и", "м"])
+        );
+        // Key in the same row is closer, than key in the next row
+        assert!(mapping["п"]["а"] > mapping["п"]["н"]);
+        assert!(mapping["п"]["е"] > mapping["п"]["н"]);
+    }
+
+    #[test]
+    fn test_switch_mapping() {
+        let qwerty = KeyboardLayout::from_name("qwerty").unwrap();
+        let jcuken = KeyboardLayout::from_name("jcuken").unwrap();
+        let mapping = qwerty.switch_mapping(&jcuken);
+        assert_eq!(mapping["q"], vec![String::from("й")]);
+        assert_eq!(mapping["Q"], vec![String::from("Й")]);
+        assert_eq!(mapping["/"], vec![String::from(".")]);
+        assert_eq!(mapping["@"], vec![String::from("\"")]);
+        assert!(!mapping.contains_key("1"));
+        assert!(!mapping.contains_key("й"));
+    }
+
+    #[test]
+    fn test_from_file() {
+        let layout = KeyboardLayout::from_file(Path::new("test_res/keyboard_layout.json")).unwrap();
+        let mapping = layout.neighbours(1.1, None);
+        assert_eq!(neighbour_set(&mapping, "a"), strings(&["b", "c"]));
+        assert_eq!(neighbour_set(&mapping, "C"), strings(&["A"]));
+        assert!(!mapping.contains_key("D"));
+
+        let err = KeyboardLayout::from_file(Path::new("test_res/good_mapping.json"));
+        assert!(err.is_err());
+        assert!(KeyboardLayout::from_name_or_file("qwerty").is_ok());
+        assert!(KeyboardLayout::from_name_or_file("test_res/keyboard_layout.json").is_ok());
+        assert!(KeyboardLayout::from_name_or_file("test_res/no_layout.json").is_err());
+    }
+}
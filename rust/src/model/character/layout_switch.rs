@@ -0,0 +1,120 @@
+use super::super::{BaseModel, Mapping};
+use super::{CharacterModel, KeyboardLayout};
+
+/// Layout switch model - emulates text, typed with wrong keyboard layout, like 'привет' -> 'ghbdtn'
+///
+/// Each char of one layout is mapped to the char on the same key of other layout
+pub struct LayoutSwitchModel {
+    /// Mapping, each char has exactly one replacement
+    model: Mapping,
+}
+
+impl LayoutSwitchModel {
+    /// Map chars of `source` layout to chars on the same keys of `target` layout,
+    /// or in reverse direction ('jcuken' -> 'qwerty' for 'qwerty' and 'jcuken'), if `reverse` is true
+    pub fn from_layouts(source: &KeyboardLayout, target: &KeyboardLayout, reverse: bool) -> Self {
+        Self::new_from_mapping(source.switch_mapping(target), reverse)
+    }
+
+    pub fn new_from_mapping(mapping: Mapping, reverse: bool) -> Self {
+        Self {
+            model: Self::build_mapping(mapping, reverse),
+        }
+    }
+
+    /// Keep first value for each key, swap direction if needed
+    /// and add uppercase pairs, if they are not defined explicitly
+    fn build_mapping(mapping: Mapping, reverse: bool) -> Mapping {
+        let mut pairs: Vec<(String, String)> = mapping
+            .into_iter()
+            .filter_map(|(key, arr)| arr.into_iter().next().map(|value| (key, value)))
+            .map(|(key, value)| match reverse {
+                true => (value, key),
+                false => (key, value),
+            })
+            .collect();
+        // Sort pairs, so the result doesn't depend on HashMap order
+        pairs.sort();
+
+        let mut layout_mapping = Mapping::with_capacity(pairs.len() * 2);
+        for (key, value) in pairs.iter() {
+            layout_mapping.insert(key.clone(), vec![value.clone()]);
+        }
+        for (key, value) in pairs {
+            let upper_key = key.to_uppercase();
+            if (upper_key != key) & !layout_mapping.contains_key(&upper_key) {
+                layout_mapping.insert(upper_key, vec![value.to_uppercase()]);
+            }
+        }
+        layout_mapping
+    }
+
+    /// Convert every char of text, chars without pair remain the same
+    pub fn switch(&self, text: &str) -> String {
+        let mut buffer = [0; 4];
+        text.chars()
+            .map(|c| match self.predict(c.encode_utf8(&mut buffer)) {
+                Some(values) => values[0].clone(),
+                None => c.to_string(),
+            })
+            .collect()
+    }
+}
+
+impl BaseModel for LayoutSwitchModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        Some(&self.model)
+    }
+}
+
+impl CharacterModel for LayoutSwitchModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn create_model(reverse: bool) -> LayoutSwitchModel {
+        LayoutSwitchModel::from_layouts(
+            &KeyboardLayout::from_name("qwerty").unwrap(),
+            &KeyboardLayout::from_name("jcuken").unwrap(),
+            reverse,
+        )
+    }
+
+    #[test]
+    fn test_from_layouts() {
+        let model = create_model(false);
+        assert_eq!(model.predict("q"), Some(&vec![String::from("й")]));
+        assert_eq!(model.predict("Q"), Some(&vec![String::from("Й")]));
+        assert_eq!(model.predict(","), Some(&vec![String::from("б")]));
+        assert_eq!(model.predict("<"), Some(&vec![String::from("Б")]));
+        assert!(!model.key_exists("й"));
+        assert_eq!(model.switch("ghbdtn? vbh!"), String::from("привет, мир!"));
+    }
+
+    #[test]
+    fn test_from_reversed_layouts() {
+        let model = create_model(true);
+        assert_eq!(model.predict("й"), Some(&vec![String::from("q")]));
+        assert_eq!(model.predict("."), Some(&vec![String::from("/")]));
+        // Explicit pair from shift layer is not overwritten by uppercase expansion
+        assert_eq!(model.predict("Ё"), Some(&vec![String::from("~")]));
+        assert!(!model.key_exists("q"));
+        assert_eq!(model.switch("Привет, Мир."), String::from("Ghbdtn? Vbh/"));
+    }
+
+    #[test]
+    fn test_create_from_mapping() {
+        let mapping = HashMap::from([
+            (
+                String::from("f"),
+                vec![String::from("а"), String::from("б")],
+            ),
+            (String::from("d"), vec![String::from("в")]),
+        ]);
+        let model = LayoutSwitchModel::new_from_mapping(mapping, false);
+        assert_eq!(model.get_stats().0, 4);
+        assert_eq!(model.switch("Fdd1"), String::from("Авв1"));
+    }
+}
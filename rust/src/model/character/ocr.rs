@@ -1,6 +1,7 @@
-use super::super::base::{BaseModel, Mapping};
+use super::super::base::{BaseModel, Mapping, WeightedMapping};
 use super::CharacterModel;
 use crate::utils;
+use std::error::Error;
 use std::path::Path;
 
 /// Ocr model - emulates ocr error, like 'l' <--> '1'
@@ -9,43 +10,16 @@ pub struct OcrModel {
     model_path: String,
     /// Mapping
     model: Option<Mapping>,
+    /// Weights of mapping values, used to draw more probable errors more often
+    weights: Option<WeightedMapping>,
 }
 
 impl OcrModel {
-    /// This function useful because if 'l' looks like '1', so '1' looks like 'l'
-    fn reverse_mapping(mapping: &mut Mapping) {
-        let mut pairs_to_insert = Vec::with_capacity(mapping.capacity() * 2);
-        for (key, vec_value) in mapping.iter() {
-            for value in vec_value {
-                let is_in_hash = mapping.get(value);
-                match is_in_hash {
-                    None => {
-                        pairs_to_insert.push((value.clone(), key.clone()));
-                    }
-                    Some(vec_not_key) => {
-                        if !vec_not_key.contains(key) {
-                            pairs_to_insert.push((value.clone(), key.clone()));
-                        }
-                    }
-                }
-            }
-        }
-
-        for (new_key, new_value) in pairs_to_insert.into_iter() {
-            if let Some(vec_of_val) = mapping.get_mut(&new_key) {
-                vec_of_val.push(new_value)
-            } else {
-                let mut new_vec_of_val = Vec::with_capacity(10);
-                new_vec_of_val.push(new_value);
-                mapping.insert(new_key.clone(), new_vec_of_val);
-            }
-        }
-    }
-
     pub fn new(model_path: String) -> Self {
         let model = Self {
             model_path,
             model: None,
+            weights: None,
         };
         model
     }
@@ -56,18 +30,31 @@ impl OcrModel {
         OcrModel {
             model_path: String::from("internal mapping"),
             model: Some(deduplicated_mapping),
+            weights: None,
+        }
+    }
+
+    pub fn new_from_weighted_mapping(mut weighted: WeightedMapping) -> Self {
+        Self::reverse_weighted_mapping(&mut weighted);
+        OcrModel {
+            model_path: String::from("internal mapping"),
+            model: Some(Self::weighted_to_mapping(&weighted)),
+            weights: Some(weighted),
         }
     }
 
     /// Lazy-file read, before this method executed -> Model = None
-    pub fn load_model(&mut self) {
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
         if let Some(_) = self.model {
-            return;
+            return Ok(());
         }
         let model_path = Path::new(&self.model_path);
-        let mut mapping_from_file = utils::read_mapping(model_path, Some(100), Some(10)).unwrap();
-        Self::reverse_mapping(&mut mapping_from_file);
-        self.model = Some(Self::deduplicate(mapping_from_file));
+        let mut weighted_from_file = utils::read_weighted_mapping(model_path, Some(100), Some(10))
+            .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        Self::reverse_weighted_mapping(&mut weighted_from_file);
+        self.model = Some(Self::weighted_to_mapping(&weighted_from_file));
+        self.weights = Some(weighted_from_file);
+        Ok(())
     }
 }
 
@@ -78,6 +65,10 @@ impl BaseModel for OcrModel {
         }
         return None;
     }
+
+    fn get_weights(&self) -> Option<&WeightedMapping> {
+        self.weights.as_ref()
+    }
 }
 
 impl CharacterModel for OcrModel {}
@@ -170,7 +161,7 @@ mod tests {
         let exp_set = transform_to_set(&expected);
         assert_eq!(obs_set, exp_set);
         assert_eq!(ocr_model.model_path, String::from("internal mapping"));
-        ocr_model.load_model();
+        ocr_model.load_model().unwrap();
         assert_eq!(obs_set, exp_set);
         assert_eq!(ocr_model.model_path, String::from("internal mapping"));
     }
@@ -180,7 +171,7 @@ mod tests {
         let mut ocr = OcrModel::new(String::from("test_res/small_mapping.json"));
         assert_eq!(ocr.get_mapping(), None);
 
-        ocr.load_model();
+        ocr.load_model().unwrap();
         let exp_hash = HashMap::from([
             (
                 String::from("A"),
@@ -194,7 +185,36 @@ mod tests {
         let obs_set = transform_to_set(ocr.get_mapping().unwrap());
         assert_eq!(obs_set, transform_to_set(&exp_hash));
 
-        ocr.load_model();
+        ocr.load_model().unwrap();
         assert_eq!(obs_set, transform_to_set(&exp_hash));
     }
+
+    #[test]
+    fn test_load_weighted_model() {
+        let mut ocr = OcrModel::new(String::from("test_res/weighted_mapping.json"));
+        ocr.load_model().unwrap();
+        assert_eq!(
+            ocr.predict("l"),
+            Some(&vec![String::from("1"), String::from("I")])
+        );
+        assert_eq!(ocr.predict("1"), Some(&vec![String::from("l")]));
+        assert!(!ocr.key_exists("x"));
+
+        let weights = ocr.get_weights().unwrap();
+        assert_eq!(weights["l"]["1"], 0.7);
+        assert_eq!(weights["l"]["I"], 0.3);
+        assert_eq!(weights["I"]["l"], 0.3);
+        assert_eq!(weights["O"]["0"], 1.0);
+    }
+
+    #[test]
+    fn test_create_from_weighted_mapping() {
+        let weighted = HashMap::from([(
+            String::from("l"),
+            HashMap::from([(String::from("1"), 0.9), (String::from("I"), 0.1)]),
+        )]);
+        let ocr = OcrModel::new_from_weighted_mapping(weighted);
+        assert_eq!(ocr.get_stats().0, 3);
+        assert_eq!(ocr.get_weights().unwrap()["1"]["l"], 0.9);
+    }
 }
@@ -1,7 +1,19 @@
+mod antonym;
 mod random;
+mod reserved;
+mod spelling;
+mod synonym;
+mod tfidf;
+mod word_embs;
 
 use super::BaseModel;
 
 pub trait WordModel: BaseModel {}
 
+pub use antonym::AntonymModel;
 pub use random::RandomWordModel;
+pub use reserved::{ReservedMatch, ReservedModel};
+pub use spelling::SpellingModel;
+pub use synonym::{PartOfSpeech, SynonymModel};
+pub use tfidf::TfIdfModel;
+pub use word_embs::{EmbeddingFormat, WordEmbsModel};
@@ -0,0 +1,120 @@
+use super::super::{BaseModel, Mapping};
+use super::WordModel;
+use crate::utils;
+use std::error::Error;
+use std::path::Path;
+
+/// Antonym model - replace word with its opposite, like 'good' <--> 'bad'
+pub struct AntonymModel {
+    /// Path on your system to load model (json-file or plain text file)
+    model_path: String,
+    /// Mapping
+    model: Option<Mapping>,
+}
+
+impl AntonymModel {
+    pub fn new(model_path: String) -> Self {
+        Self {
+            model_path,
+            model: None,
+        }
+    }
+
+    pub fn new_from_mapping(mut mapping: Mapping) -> Self {
+        Self::reverse_mapping(&mut mapping);
+        let deduplicated_mapping = Self::deduplicate(mapping);
+        AntonymModel {
+            model_path: String::from("internal mapping"),
+            model: Some(deduplicated_mapping),
+        }
+    }
+
+    /// Lazy-file read, before this method executed -> Model = None
+    ///
+    /// Json-file is expected in format `{"word": ["antonym", ...]}`,
+    /// any other file - in plain text format, `word antonym ...` on each line.
+    /// Pairs are symmetric: if 'bad' is antonym of 'good', so 'good' is antonym of 'bad'
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.model.is_some() {
+            return Ok(());
+        }
+        let model_path = Path::new(&self.model_path);
+        let is_json = model_path.extension().is_some_and(|ext| ext == "json");
+        let mut mapping_from_file = match is_json {
+            true => utils::read_mapping(model_path, Some(1000), Some(5)),
+            false => utils::read_text_mapping(model_path, Some(1000), Some(5)),
+        }
+        .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        Self::reverse_mapping(&mut mapping_from_file);
+        self.model = Some(Self::deduplicate(mapping_from_file));
+        Ok(())
+    }
+}
+
+impl BaseModel for AntonymModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        if let Some(model) = &self.model {
+            return Some(model);
+        }
+        None
+    }
+}
+
+impl WordModel for AntonymModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transform_to_set;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_load_text_model() {
+        let mut model = AntonymModel::new(String::from("test_res/antonyms.txt"));
+        assert_eq!(model.get_mapping(), None);
+
+        model.load_model().unwrap();
+        let mapping_set = transform_to_set(model.get_mapping().unwrap());
+        assert_eq!(
+            mapping_set.get("good").unwrap(),
+            &HashSet::from([String::from("bad"), String::from("evil")])
+        );
+        assert_eq!(
+            mapping_set.get("bad").unwrap(),
+            &HashSet::from([String::from("good")])
+        );
+        assert_eq!(
+            mapping_set.get("cold").unwrap(),
+            &HashSet::from([String::from("hot")])
+        );
+        assert_eq!(
+            mapping_set.get("unhappy").unwrap(),
+            &HashSet::from([String::from("happy")])
+        );
+    }
+
+    #[test]
+    fn test_load_json_model() {
+        let mut model = AntonymModel::new(String::from("test_res/antonyms.json"));
+        model.load_model().unwrap();
+        let expected = HashMap::from([
+            (String::from("good"), vec![String::from("bad")]),
+            (String::from("bad"), vec![String::from("good")]),
+            (String::from("hot"), vec![String::from("cold")]),
+            (String::from("cold"), vec![String::from("hot")]),
+        ]);
+        assert_eq!(
+            transform_to_set(model.get_mapping().unwrap()),
+            transform_to_set(&expected)
+        );
+    }
+
+    #[test]
+    fn test_create_from_mapping() {
+        let mapping = HashMap::from([(String::from("up"), vec![String::from("down")])]);
+        let model = AntonymModel::new_from_mapping(mapping);
+        assert_eq!(model.predict("up"), Some(&vec![String::from("down")]));
+        assert_eq!(model.predict("down"), Some(&vec![String::from("up")]));
+        assert_eq!(model.model_path, String::from("internal mapping"));
+    }
+}
@@ -0,0 +1,301 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use super::super::{BaseModel, Mapping};
+use super::WordModel;
+use crate::doc::{AlphanumericTokenizer, Doc, Token, TokenType, Tokenizer};
+
+/// Reserved term, split into tokens
+struct ReservedPhrase {
+    /// Tokens of term (kind & normalized value)
+    tokens: Vec<(TokenType, String)>,
+    /// Index of group, that term belongs to
+    group_idx: usize,
+    /// Index of term inside group
+    term_idx: usize,
+}
+
+/// Reserved term, found in sequence of tokens
+#[derive(Debug, PartialEq)]
+pub struct ReservedMatch {
+    /// Index of first token of term
+    pub start: usize,
+    /// Index of token after term
+    pub end: usize,
+    /// Index of group, that term belongs to
+    pub group_idx: usize,
+    /// Index of term inside group
+    pub term_idx: usize,
+}
+
+/// Reserved model - swap reserved term with other variant of it, like 'FW' <--> 'Fwd' <--> 'Forward'
+///
+/// Terms can consist of several words, like 'New York' <--> 'NYC'
+pub struct ReservedModel {
+    /// If false, terms are matched ignoring case
+    case_sensitive: bool,
+    /// Groups of terms, that can replace each other
+    reserved_tokens: Vec<Vec<String>>,
+    /// Every term split into tokens, sorted from longest to shortest
+    phrases: Vec<ReservedPhrase>,
+    /// Normalized first token of term -> indexes of phrases, that start with it
+    first_token_index: HashMap<String, Vec<usize>>,
+    /// Mapping, term -> other terms of its group
+    model: Mapping,
+}
+
+impl ReservedModel {
+    pub fn new(reserved_tokens: Vec<Vec<String>>, case_sensitive: bool) -> Self {
+        Self::new_with_tokenizer(reserved_tokens, case_sensitive, &AlphanumericTokenizer)
+    }
+
+    /// Terms are split with the same tokenizer, that is used for augmented Doc
+    pub fn new_with_tokenizer(
+        reserved_tokens: Vec<Vec<String>>,
+        case_sensitive: bool,
+        tokenizer: &dyn Tokenizer,
+    ) -> Self {
+        let mut model = Self {
+            case_sensitive,
+            reserved_tokens,
+            phrases: Vec::new(),
+            first_token_index: HashMap::new(),
+            model: Mapping::new(),
+        };
+        model.build(tokenizer);
+        model
+    }
+
+    fn normalize(&self, value: &str) -> String {
+        match self.case_sensitive {
+            true => value.to_string(),
+            false => value.to_lowercase(),
+        }
+    }
+
+    /// Split every term into tokens the same way as Doc does
+    fn build(&mut self, tokenizer: &dyn Tokenizer) {
+        let mut phrases = Vec::new();
+        let mut mapping = Mapping::with_capacity(self.reserved_tokens.len() * 2);
+        for (group_idx, group) in self.reserved_tokens.iter().enumerate() {
+            for (term_idx, term) in group.iter().enumerate() {
+                let doc = Doc::new_with_tokenizer(term, tokenizer);
+                let tokens: Vec<(TokenType, String)> = doc
+                    .get_original_tokens()
+                    .into_iter()
+                    .map(|t| (t.kind().clone(), self.normalize(t.token())))
+                    .collect();
+                if tokens.is_empty() {
+                    continue;
+                }
+                phrases.push(ReservedPhrase {
+                    tokens,
+                    group_idx,
+                    term_idx,
+                });
+                let others = group.iter().filter(|x| *x != term).cloned();
+                mapping
+                    .entry(self.normalize(term))
+                    .or_default()
+                    .extend(others);
+            }
+        }
+        phrases.sort_by_key(|phrase| Reverse(phrase.tokens.len()));
+
+        let mut first_token_index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (idx, phrase) in phrases.iter().enumerate() {
+            first_token_index
+                .entry(phrase.tokens[0].1.clone())
+                .or_default()
+                .push(idx);
+        }
+        self.phrases = phrases;
+        self.first_token_index = first_token_index;
+        self.model = Self::deduplicate(mapping);
+    }
+
+    /// Compare phrase with tokens, space tokens are matched by kind only
+    fn phrase_matches(&self, phrase: &ReservedPhrase, tokens: &[&Token]) -> bool {
+        if phrase.tokens.len() > tokens.len() {
+            return false;
+        }
+        phrase
+            .tokens
+            .iter()
+            .zip(tokens.iter())
+            .all(|((kind, value), token)| match (kind, token.kind()) {
+                (TokenType::SpaceToken, TokenType::SpaceToken) => true,
+                (TokenType::SpaceToken, _) => false,
+                (_, _) => *value == self.normalize(token.token()),
+            })
+    }
+
+    /// Find non-overlapping reserved terms in tokens, longest terms have priority
+    pub fn find_matches(&self, tokens: &[&Token]) -> Vec<ReservedMatch> {
+        let mut matches = Vec::new();
+        let mut pos = 0;
+        while pos < tokens.len() {
+            let candidates = self
+                .first_token_index
+                .get(&self.normalize(tokens[pos].token()));
+            let mut matched_len = 0;
+            if let Some(phrase_idxs) = candidates {
+                for phrase_idx in phrase_idxs {
+                    let phrase = &self.phrases[*phrase_idx];
+                    if self.phrase_matches(phrase, &tokens[pos..]) {
+                        matched_len = phrase.tokens.len();
+                        matches.push(ReservedMatch {
+                            start: pos,
+                            end: pos + matched_len,
+                            group_idx: phrase.group_idx,
+                            term_idx: phrase.term_idx,
+                        });
+                        break;
+                    }
+                }
+            }
+            pos += usize::max(matched_len, 1);
+        }
+        matches
+    }
+
+    /// Get other terms of group, that can replace found term
+    pub fn get_variants(&self, found: &ReservedMatch) -> Vec<&String> {
+        match self.reserved_tokens.get(found.group_idx) {
+            Some(group) => group
+                .iter()
+                .enumerate()
+                .filter(|(idx, term)| *idx != found.term_idx && **term != group[found.term_idx])
+                .map(|(_, term)| term)
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+}
+
+impl BaseModel for ReservedModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        Some(&self.model)
+    }
+
+    fn key_exists(&self, data: &str) -> bool {
+        self.model.contains_key(&self.normalize(data))
+    }
+
+    fn predict(&self, data: &str) -> Option<&Vec<String>> {
+        self.model.get(&self.normalize(data))
+    }
+}
+
+impl WordModel for ReservedModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transform_to_set;
+    use std::collections::HashSet;
+
+    fn create_groups() -> Vec<Vec<String>> {
+        vec![
+            vec![
+                String::from("FW"),
+                String::from("Fwd"),
+                String::from("Forward"),
+            ],
+            vec![String::from("New York"), String::from("NYC")],
+            vec![String::from("New"), String::from("Fresh")],
+        ]
+    }
+
+    #[test]
+    fn test_mapping() {
+        let model = ReservedModel::new(create_groups(), true);
+        let mapping_set = transform_to_set(model.get_mapping().unwrap());
+        assert_eq!(
+            mapping_set.get("FW").unwrap(),
+            &HashSet::from([String::from("Fwd"), String::from("Forward")])
+        );
+        assert_eq!(
+            mapping_set.get("New York").unwrap(),
+            &HashSet::from([String::from("NYC")])
+        );
+        assert!(model.key_exists("NYC"));
+        assert!(!model.key_exists("nyc"));
+    }
+
+    #[test]
+    fn test_find_multi_word_matches() {
+        let model = ReservedModel::new(create_groups(), true);
+        let doc = Doc::new(&String::from("FW: New York and New Jersey, NYC"));
+        let tokens = doc.get_original_tokens();
+        let found: Vec<(usize, usize, usize)> = model
+            .find_matches(&tokens)
+            .into_iter()
+            .map(|m| (m.start, m.end, m.group_idx))
+            .collect();
+        // Longest term 'New York' preferred over 'New'
+        assert_eq!(found, vec![(0, 1, 0), (3, 6, 1), (9, 10, 2), (14, 15, 1)]);
+    }
+
+    #[test]
+    fn test_find_matches_case_insensitive() {
+        let doc = Doc::new(&String::from("fwd: new   york"));
+        let tokens = doc.get_original_tokens();
+
+        let model = ReservedModel::new(create_groups(), true);
+        assert!(model.find_matches(&tokens).is_empty());
+
+        let model = ReservedModel::new(create_groups(), false);
+        assert!(model.key_exists("nyc"));
+        let found: Vec<(usize, usize, usize)> = model
+            .find_matches(&tokens)
+            .into_iter()
+            .map(|m| (m.start, m.end, m.group_idx))
+            .collect();
+        // Only one space token is expected between 'new' and 'york'
+        assert_eq!(found, vec![(0, 1, 0), (3, 4, 2)]);
+    }
+
+    #[test]
+    fn test_get_variants() {
+        let model = ReservedModel::new(create_groups(), false);
+        let doc = Doc::new(&String::from("Fwd: NYC"));
+        let found = model.find_matches(&doc.get_original_tokens());
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            model.get_variants(&found[0]),
+            vec![&String::from("FW"), &String::from("Forward")]
+        );
+        assert_eq!(
+            model.get_variants(&found[1]),
+            vec![&String::from("New York")]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_with_tokenizer() {
+        let groups = vec![vec![String::from("e-mail"), String::from("mail")]];
+        let tokenizer = crate::doc::WhitespaceTokenizer;
+        let model = ReservedModel::new_with_tokenizer(groups, true, &tokenizer);
+        let doc = Doc::new_with_tokenizer("send e-mail", &tokenizer);
+        let found: Vec<(usize, usize)> = model
+            .find_matches(&doc.get_original_tokens())
+            .into_iter()
+            .map(|m| (m.start, m.end))
+            .collect();
+        assert_eq!(found, vec![(2, 3)]);
+    }
+
+    #[test]
+    fn test_find_no_matches() {
+        let model = ReservedModel::new(create_groups(), true);
+        let doc = Doc::new(&String::from("Nothing to match"));
+        assert!(model.find_matches(&doc.get_original_tokens()).is_empty());
+        let doc = Doc::new(&String::from(""));
+        assert!(model.find_matches(&doc.get_original_tokens()).is_empty());
+    }
+}
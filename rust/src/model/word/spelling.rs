@@ -0,0 +1,122 @@
+use super::super::{BaseModel, Mapping};
+use super::WordModel;
+use crate::utils;
+use std::error::Error;
+use std::path::Path;
+
+/// Spelling model - emulates common misspellings, like 'because' -> 'becuase'
+pub struct SpellingModel {
+    /// Also add pairs 'misspelling' -> 'correct word' to mapping
+    include_reverse: bool,
+    /// Path on your system to load model (json-file or plain text file)
+    model_path: String,
+    /// Mapping
+    model: Option<Mapping>,
+}
+
+impl SpellingModel {
+    pub fn new(model_path: String, include_reverse: bool) -> Self {
+        Self {
+            include_reverse,
+            model_path,
+            model: None,
+        }
+    }
+
+    pub fn new_from_mapping(mut mapping: Mapping, include_reverse: bool) -> Self {
+        if include_reverse {
+            Self::reverse_mapping(&mut mapping);
+        }
+        let deduplicated_mapping = Self::deduplicate(mapping);
+        SpellingModel {
+            include_reverse,
+            model_path: String::from("internal mapping"),
+            model: Some(deduplicated_mapping),
+        }
+    }
+
+    /// Lazy-file read, before this method executed -> Model = None
+    ///
+    /// Json-file is expected in format `{"word": ["misspelling", ...]}`,
+    /// any other file - in plain text format, `word misspelling ...` on each line
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.model.is_some() {
+            return Ok(());
+        }
+        let model_path = Path::new(&self.model_path);
+        let is_json = model_path.extension().is_some_and(|ext| ext == "json");
+        let mut mapping_from_file = match is_json {
+            true => utils::read_mapping(model_path, Some(1000), Some(5)),
+            false => utils::read_text_mapping(model_path, Some(1000), Some(5)),
+        }
+        .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        if self.include_reverse {
+            Self::reverse_mapping(&mut mapping_from_file);
+        }
+        self.model = Some(Self::deduplicate(mapping_from_file));
+        Ok(())
+    }
+}
+
+impl BaseModel for SpellingModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        if let Some(model) = &self.model {
+            return Some(model);
+        }
+        None
+    }
+}
+
+impl WordModel for SpellingModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transform_to_set;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_load_json_model() {
+        let mut model = SpellingModel::new(String::from("test_res/spelling_en.json"), false);
+        assert_eq!(model.get_mapping(), None);
+
+        model.load_model().unwrap();
+        let expected = HashMap::from([
+            (
+                String::from("because"),
+                vec![String::from("becuase"), String::from("becasue")],
+            ),
+            (String::from("receive"), vec![String::from("recieve")]),
+            (String::from("the"), vec![String::from("teh")]),
+        ]);
+        let obs_set = transform_to_set(model.get_mapping().unwrap());
+        assert_eq!(obs_set, transform_to_set(&expected));
+    }
+
+    #[test]
+    fn test_load_text_model_with_reverse() {
+        let mut model = SpellingModel::new(String::from("test_res/spelling_en.txt"), true);
+        model.load_model().unwrap();
+        assert!(model.key_exists("because"));
+        assert!(model.key_exists("becuase"));
+        assert!(model.key_exists("receve"));
+        assert!(!model.key_exists("lonely"));
+        assert_eq!(model.predict("teh"), Some(&vec![String::from("the")]));
+        assert_eq!(
+            model.predict("recieve"),
+            Some(&vec![String::from("receive")])
+        );
+    }
+
+    #[test]
+    fn test_create_from_mapping() {
+        let mapping = HashMap::from([(
+            String::from("which"),
+            vec![String::from("wich"), String::from("wich")],
+        )]);
+        let model = SpellingModel::new_from_mapping(mapping, false);
+        assert_eq!(model.predict("which"), Some(&vec![String::from("wich")]));
+        assert!(!model.key_exists("wich"));
+        assert_eq!(model.model_path, String::from("internal mapping"));
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::super::{BaseModel, Mapping};
+use super::WordModel;
+
+/// Part of speech of synset, as it defined in WordNet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+}
+
+impl PartOfSpeech {
+    /// Parse WordNet tag ('n', 'v', 'a', 's', 'r') or full name ('noun', 'verb', ...)
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match &tag.trim().to_lowercase()[..] {
+            "n" | "noun" => Some(PartOfSpeech::Noun),
+            "v" | "verb" => Some(PartOfSpeech::Verb),
+            "a" | "s" | "adj" | "adjective" => Some(PartOfSpeech::Adjective),
+            "r" | "adv" | "adverb" => Some(PartOfSpeech::Adverb),
+            _ => None,
+        }
+    }
+}
+
+/// Set of lemmas with the same meaning
+type Synset = (Option<PartOfSpeech>, Vec<String>);
+
+/// Synonym model - replace word with other lemma from the same synset
+pub struct SynonymModel {
+    /// Path on your system to load thesaurus file
+    model_path: String,
+    /// If set, only synsets with this part of speech used in `predict`
+    pos: Option<PartOfSpeech>,
+    /// Mapping, lemma -> synonyms from synsets of any part of speech
+    model: Option<Mapping>,
+    /// Mapping for every part of speech
+    pos_model: HashMap<PartOfSpeech, Mapping>,
+}
+
+impl SynonymModel {
+    pub fn new(model_path: String, pos: Option<PartOfSpeech>) -> Self {
+        Self {
+            model_path,
+            pos,
+            model: None,
+            pos_model: HashMap::new(),
+        }
+    }
+
+    /// Multi-word lemmas in WordNet use '_' as separator
+    fn normalize_lemma(lemma: &str) -> String {
+        lemma.trim().replace('_', " ")
+    }
+
+    /// Read WordNet prolog export (`wn_s.pl`)
+    ///
+    /// Expected format: `s(synset_id,w_num,'word',ss_type,sense_number,tag_count).` on each line
+    fn read_prolog_synsets(path: &Path) -> Result<Vec<Synset>, Box<dyn Error>> {
+        let file_content = fs::read_to_string(path)?;
+        let mut synsets: Vec<Synset> = Vec::new();
+        let mut synset_positions: HashMap<String, usize> = HashMap::new();
+
+        for line in file_content.lines() {
+            let line = line.trim();
+            if !line.starts_with("s(") {
+                continue;
+            }
+            let (first_quote, last_quote) = match (line.find('\''), line.rfind('\'')) {
+                (Some(first), Some(last)) if first < last => (first, last),
+                (_, _) => continue,
+            };
+            let synset_id = match line[2..first_quote].split(',').next() {
+                Some(value) => value.to_string(),
+                None => continue,
+            };
+            let lemma =
+                Self::normalize_lemma(&line[first_quote + 1..last_quote].replace("''", "'"));
+            let pos = line[last_quote + 1..]
+                .split(',')
+                .nth(1)
+                .and_then(PartOfSpeech::from_tag);
+
+            match synset_positions.get(&synset_id) {
+                Some(position) => synsets[*position].1.push(lemma),
+                None => {
+                    synset_positions.insert(synset_id, synsets.len());
+                    synsets.push((pos, vec![lemma]));
+                }
+            }
+        }
+        Ok(synsets)
+    }
+
+    /// Read json list of synsets
+    ///
+    /// Expected format: `[{"pos": "n", "lemmas": ["car", "auto"]}, ...]`, "pos" is optional
+    fn read_json_synsets(path: &Path) -> Result<Vec<Synset>, Box<dyn Error>> {
+        let file_content = fs::read_to_string(path)?;
+        let json_vec: Vec<Value> = serde_json::from_str(&file_content)?;
+        let mut synsets: Vec<Synset> = Vec::with_capacity(json_vec.len());
+
+        for raw_synset in json_vec {
+            let pos = raw_synset
+                .get("pos")
+                .and_then(Value::as_str)
+                .and_then(PartOfSpeech::from_tag);
+            if let Some(Value::Array(json_lemmas)) = raw_synset.get("lemmas") {
+                let lemmas: Vec<String> = json_lemmas
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(Self::normalize_lemma)
+                    .collect();
+                synsets.push((pos, lemmas));
+            }
+        }
+        Ok(synsets)
+    }
+
+    /// Read tsv file of synsets
+    ///
+    /// Expected format: `pos<TAB>lemma<TAB>lemma...` on each line, pos column may be empty
+    fn read_tsv_synsets(path: &Path) -> Result<Vec<Synset>, Box<dyn Error>> {
+        let file_content = fs::read_to_string(path)?;
+        let mut synsets: Vec<Synset> = Vec::new();
+
+        for line in file_content.lines() {
+            let mut columns = line.split('\t');
+            let pos = match columns.next() {
+                Some(tag) => PartOfSpeech::from_tag(tag),
+                None => continue,
+            };
+            let lemmas: Vec<String> = columns
+                .map(Self::normalize_lemma)
+                .filter(|lemma| !lemma.is_empty())
+                .collect();
+            synsets.push((pos, lemmas));
+        }
+        Ok(synsets)
+    }
+
+    /// Add every lemma from synset -> other lemmas of synset to mapping
+    fn insert_synset(mapping: &mut Mapping, lemmas: &Vec<String>) {
+        for lemma in lemmas {
+            let synonyms = lemmas.iter().filter(|x| *x != lemma).cloned();
+            mapping
+                .entry(lemma.clone())
+                .or_insert_with(|| Vec::with_capacity(lemmas.len()))
+                .extend(synonyms);
+        }
+    }
+
+    fn build_from_synsets(&mut self, synsets: Vec<Synset>) {
+        let mut mapping = Mapping::with_capacity(synsets.len() * 2);
+        let mut pos_mapping: HashMap<PartOfSpeech, Mapping> = HashMap::with_capacity(4);
+        for (pos, lemmas) in synsets.iter() {
+            if lemmas.len() < 2 {
+                continue;
+            }
+            Self::insert_synset(&mut mapping, lemmas);
+            if let Some(pos) = pos {
+                let pos_entry = pos_mapping.entry(*pos).or_default();
+                Self::insert_synset(pos_entry, lemmas);
+            }
+        }
+        mapping.retain(|_, synonyms| !synonyms.is_empty());
+        self.model = Some(Self::deduplicate(mapping));
+        self.pos_model = pos_mapping
+            .into_iter()
+            .map(|(pos, mapping)| (pos, Self::deduplicate(mapping)))
+            .collect();
+    }
+
+    /// Create model from vector of synsets (part of speech, lemmas)
+    pub fn from_synsets(
+        synsets: Vec<(Option<PartOfSpeech>, Vec<String>)>,
+        pos: Option<PartOfSpeech>,
+    ) -> Self {
+        let mut model = Self::new(String::from("internal synsets"), pos);
+        model.build_from_synsets(synsets);
+        model
+    }
+
+    /// Lazy-file read, before this method executed -> Model = None
+    ///
+    /// File format detected by extension: '.pl' - WordNet prolog, '.json' - json, other - tsv
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.model.is_some() {
+            return Ok(());
+        }
+        let model_path = Path::new(&self.model_path);
+        let extension = model_path.extension().and_then(|ext| ext.to_str());
+        let synsets = match extension {
+            Some("pl") => Self::read_prolog_synsets(model_path),
+            Some("json") => Self::read_json_synsets(model_path),
+            _ => Self::read_tsv_synsets(model_path),
+        }
+        .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        self.build_from_synsets(synsets);
+        Ok(())
+    }
+
+    /// Lookup by lemma among synsets of specific part of speech
+    pub fn predict_by_pos(&self, data: &str, pos: PartOfSpeech) -> Option<&Vec<String>> {
+        let mapping = self.pos_model.get(&pos)?;
+        mapping
+            .get(data)
+            .or_else(|| mapping.get(&data.to_lowercase()))
+    }
+}
+
+impl BaseModel for SynonymModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        match self.pos {
+            Some(pos) => self.pos_model.get(&pos),
+            None => self.model.as_ref(),
+        }
+    }
+
+    /// Lemmas are looked up as is, then in lowercase
+    fn key_exists(&self, data: &str) -> bool {
+        self.predict(data).is_some()
+    }
+
+    /// Lemmas are looked up as is, then in lowercase
+    fn predict(&self, data: &str) -> Option<&Vec<String>> {
+        let mapping = self.get_mapping()?;
+        mapping
+            .get(data)
+            .or_else(|| mapping.get(&data.to_lowercase()))
+    }
+}
+
+impl WordModel for SynonymModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn to_set(values: Option<&Vec<String>>) -> HashSet<String> {
+        HashSet::from_iter(values.unwrap().iter().cloned())
+    }
+
+    fn str_set(values: &[&str]) -> HashSet<String> {
+        HashSet::from_iter(values.iter().map(|x| x.to_string()))
+    }
+
+    #[test]
+    fn test_load_prolog_model() {
+        let mut model = SynonymModel::new(String::from("test_res/wn_s.pl"), None);
+        assert_eq!(model.get_mapping(), None);
+        model.load_model().unwrap();
+
+        assert_eq!(
+            to_set(model.predict("car")),
+            str_set(&["auto", "automobile", "motorcar"])
+        );
+        assert_eq!(
+            to_set(model.predict("hot dog")),
+            str_set(&["hotdog", "red hot"])
+        );
+        assert_eq!(to_set(model.predict("o'clock")), str_set(&["hour"]));
+        assert_eq!(
+            to_set(model.predict("fast")),
+            str_set(&["quick", "quickly"])
+        );
+        assert!(!model.key_exists("unknown"));
+    }
+
+    #[test]
+    fn test_lookup_by_pos() {
+        let mut model = SynonymModel::new(String::from("test_res/wn_s.pl"), None);
+        model.load_model().unwrap();
+
+        assert_eq!(
+            to_set(model.predict_by_pos("fast", PartOfSpeech::Adjective)),
+            str_set(&["quick"])
+        );
+        assert_eq!(
+            to_set(model.predict_by_pos("fast", PartOfSpeech::Adverb)),
+            str_set(&["quickly"])
+        );
+        assert_eq!(model.predict_by_pos("fast", PartOfSpeech::Verb), None);
+    }
+
+    #[test]
+    fn test_model_restricted_by_pos() {
+        let mut model =
+            SynonymModel::new(String::from("test_res/wn_s.pl"), Some(PartOfSpeech::Verb));
+        model.load_model().unwrap();
+
+        assert!(model.key_exists("go"));
+        assert!(!model.key_exists("car"));
+        assert!(!model.key_exists("fast"));
+    }
+
+    #[test]
+    fn test_lowercase_lookup() {
+        let mut model = SynonymModel::new(String::from("test_res/wn_s.pl"), None);
+        model.load_model().unwrap();
+
+        assert!(model.key_exists("Car"));
+        assert_eq!(model.predict("Car"), model.predict("car"));
+    }
+
+    #[test]
+    fn test_load_json_and_tsv_model() {
+        let mut json_model = SynonymModel::new(String::from("test_res/synonyms.json"), None);
+        json_model.load_model().unwrap();
+        let mut tsv_model = SynonymModel::new(String::from("test_res/synonyms.tsv"), None);
+        tsv_model.load_model().unwrap();
+
+        for model in [json_model, tsv_model] {
+            assert_eq!(
+                to_set(model.predict("car")),
+                str_set(&["auto", "automobile"])
+            );
+            assert_eq!(to_set(model.predict("big")), str_set(&["large", "huge"]));
+            assert_eq!(
+                to_set(model.predict_by_pos("go", PartOfSpeech::Verb)),
+                str_set(&["travel"])
+            );
+            assert_eq!(model.predict_by_pos("big", PartOfSpeech::Adjective), None);
+        }
+    }
+
+    #[test]
+    fn test_from_synsets() {
+        let model = SynonymModel::from_synsets(
+            vec![
+                (
+                    Some(PartOfSpeech::Noun),
+                    vec![String::from("film"), String::from("movie")],
+                ),
+                (Some(PartOfSpeech::Noun), vec![String::from("lonely")]),
+            ],
+            None,
+        );
+        assert_eq!(model.predict("film"), Some(&vec![String::from("movie")]));
+        assert!(!model.key_exists("lonely"));
+    }
+}
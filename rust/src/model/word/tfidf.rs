@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::super::{BaseModel, Mapping};
+use super::WordModel;
+use crate::doc::{Doc, Tokenizer};
+
+/// TF-IDF model - score words by how informative they are in corpus
+///
+/// Words with low score are replaced, replacements are drawn from words with the highest score
+pub struct TfIdfModel {
+    /// Path on your system to load model (json-file, created by `save`)
+    model_path: String,
+    /// Number of words with the highest score, that can be used as replacement
+    top_k: usize,
+    /// Inverse document frequency of every word
+    idf: HashMap<String, f32>,
+    /// Average TF-IDF score of every word among documents, that contain it
+    tfidf: HashMap<String, f32>,
+    /// top_k words with the highest TF-IDF score
+    candidates: Vec<String>,
+}
+
+impl TfIdfModel {
+    pub fn new(model_path: String, top_k: usize) -> Self {
+        Self {
+            model_path,
+            top_k,
+            idf: HashMap::new(),
+            tfidf: HashMap::new(),
+            candidates: Vec::new(),
+        }
+    }
+
+    /// Words are taken from Doc tokenization and lowercased
+    fn tokenize(text: &str, tokenizer: &dyn Tokenizer) -> Vec<String> {
+        let mut doc = Doc::new_with_tokenizer(text, tokenizer);
+        doc.get_word_tokens_with_indexes(false)
+            .into_iter()
+            .map(|(_, handler)| handler.get_current().token().to_lowercase())
+            .collect()
+    }
+
+    /// Train model on corpus, each element of corpus is a separate document
+    ///
+    /// IDF is calculated as `ln(N / df)`, so words from every document get zero score.
+    /// `tokenizer` must be the same, augmented texts are split with
+    pub fn train(corpus: &[String], top_k: usize, tokenizer: &dyn Tokenizer) -> Self {
+        let docs_count = corpus.len() as f32;
+        let mut doc_freq: HashMap<String, usize> = HashMap::new();
+        let mut term_freqs: Vec<HashMap<String, f32>> = Vec::with_capacity(corpus.len());
+
+        for text in corpus {
+            let words = Self::tokenize(text, tokenizer);
+            let mut counts: HashMap<String, f32> = HashMap::new();
+            for word in words.iter() {
+                *counts.entry(word.clone()).or_insert(0.0) += 1.0;
+            }
+            for (word, count) in counts.iter_mut() {
+                *doc_freq.entry(word.clone()).or_insert(0) += 1;
+                *count /= words.len() as f32;
+            }
+            term_freqs.push(counts);
+        }
+
+        let idf: HashMap<String, f32> = doc_freq
+            .iter()
+            .map(|(word, df)| {
+                let value = f32::ln(docs_count / *df as f32);
+                (word.clone(), value)
+            })
+            .collect();
+
+        let mut tfidf: HashMap<String, f32> = HashMap::with_capacity(idf.len());
+        for counts in term_freqs {
+            for (word, tf) in counts {
+                *tfidf.entry(word.clone()).or_insert(0.0) += tf * idf[&word];
+            }
+        }
+        for (word, score) in tfidf.iter_mut() {
+            *score /= doc_freq[word] as f32;
+        }
+
+        let mut model = Self::new(String::from("internal corpus"), top_k);
+        model.idf = idf;
+        model.tfidf = tfidf;
+        model.update_candidates();
+        model
+    }
+
+    fn update_candidates(&mut self) {
+        let mut words: Vec<(&String, &f32)> = self.tfidf.iter().collect();
+        // Sort by word too, so candidates don't depend on HashMap order
+        words.sort_by(|a, b| b.1.total_cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        self.candidates = words
+            .into_iter()
+            .take(self.top_k)
+            .map(|(word, _)| word.clone())
+            .collect();
+    }
+
+    fn scores_to_json(scores: &HashMap<String, f32>) -> Value {
+        let mut json_map = Map::with_capacity(scores.len());
+        for (word, score) in scores {
+            json_map.insert(word.clone(), Value::from(*score));
+        }
+        Value::Object(json_map)
+    }
+
+    fn scores_from_json(value: Option<&Value>) -> HashMap<String, f32> {
+        let mut scores = HashMap::new();
+        if let Some(Value::Object(json_map)) = value {
+            for (word, raw_score) in json_map {
+                if let Some(score) = raw_score.as_f64() {
+                    scores.insert(word.clone(), score as f32);
+                }
+            }
+        }
+        scores
+    }
+
+    /// Save model to json-file in format `{"idf": {"word": 1.0, ...}, "tfidf": {"word": 0.1, ...}}`
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut json_map = Map::with_capacity(2);
+        json_map.insert(String::from("idf"), Self::scores_to_json(&self.idf));
+        json_map.insert(String::from("tfidf"), Self::scores_to_json(&self.tfidf));
+        fs::write(path, serde_json::to_string(&json_map)?)?;
+        Ok(())
+    }
+
+    /// Lazy-file read, before this method executed -> model is empty
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if !self.tfidf.is_empty() {
+            return Ok(());
+        }
+        let json_map: Map<String, Value> = fs::read_to_string(&self.model_path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| serde_json::from_str(&content).map_err(|err| err.to_string()))
+            .map_err(|err| format!("{}: {}", self.model_path, err))?;
+        self.idf = Self::scores_from_json(json_map.get("idf"));
+        self.tfidf = Self::scores_from_json(json_map.get("tfidf"));
+        self.update_candidates();
+        Ok(())
+    }
+
+    /// TF-IDF score of word, if it was met in corpus
+    pub fn get_score(&self, word: &str) -> Option<f32> {
+        self.tfidf.get(&word.to_lowercase()).copied()
+    }
+
+    /// Inverse document frequency of word, if it was met in corpus
+    pub fn get_idf(&self, word: &str) -> Option<f32> {
+        self.idf.get(&word.to_lowercase()).copied()
+    }
+
+    /// All words, that model knows
+    pub fn get_vocab(&self) -> HashSet<&String> {
+        self.tfidf.keys().collect()
+    }
+}
+
+impl BaseModel for TfIdfModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        None
+    }
+
+    fn get_stats(&self) -> (usize, usize, Vec<(usize, usize)>) {
+        (self.tfidf.len(), self.tfidf.capacity(), Vec::new())
+    }
+
+    fn key_exists(&self, data: &str) -> bool {
+        self.tfidf.contains_key(&data.to_lowercase())
+    }
+
+    /// Any word can be replaced by one of top_k candidates
+    fn predict(&self, _: &str) -> Option<&Vec<String>> {
+        match self.candidates.len() {
+            0 => None,
+            _ => Some(&self.candidates),
+        }
+    }
+}
+
+impl WordModel for TfIdfModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::{AlphanumericTokenizer, WhitespaceTokenizer};
+
+    fn create_corpus() -> Vec<String> {
+        vec![
+            String::from("The cat sat on the mat"),
+            String::from("The dog ate the bone"),
+            String::from("The bird sang"),
+        ]
+    }
+
+    #[test]
+    fn test_train() {
+        let model = TfIdfModel::train(&create_corpus(), 3, &AlphanumericTokenizer);
+        assert_eq!(model.get_stats().0, 10);
+        assert!(model.key_exists("the"));
+        assert!(model.key_exists("The"));
+        assert!(!model.key_exists("fish"));
+        assert_eq!(model.get_idf("the"), Some(0.0));
+        assert!(model.get_idf("cat").unwrap() > model.get_idf("the").unwrap());
+        assert!(model.get_score("bird").unwrap() > model.get_score("cat").unwrap());
+        assert!(model.get_score("cat").unwrap() > model.get_score("the").unwrap());
+        assert_eq!(
+            model.predict("any"),
+            Some(&vec![
+                String::from("bird"),
+                String::from("sang"),
+                String::from("ate")
+            ])
+        );
+    }
+
+    #[test]
+    fn test_train_with_tokenizer() {
+        let corpus = [String::from("It's done, isn't it?")];
+        let model = TfIdfModel::train(&corpus, 3, &WhitespaceTokenizer);
+        assert!(model.key_exists("It's"));
+        assert!(model.key_exists("done,"));
+        assert!(!model.key_exists("isn"));
+
+        let model = TfIdfModel::train(&corpus, 3, &AlphanumericTokenizer);
+        assert!(model.key_exists("isn"));
+        assert!(!model.key_exists("done,"));
+    }
+
+    #[test]
+    fn test_train_empty_corpus() {
+        let model = TfIdfModel::train(&[String::new()], 3, &AlphanumericTokenizer);
+        assert_eq!(model.get_stats().0, 0);
+        assert_eq!(model.predict("any"), None);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let model = TfIdfModel::train(&create_corpus(), 5, &AlphanumericTokenizer);
+        let path = std::env::temp_dir().join(format!(
+            "fasttextaug_test_tfidf_{}.json",
+            std::process::id()
+        ));
+        model.save(&path).unwrap();
+
+        let mut loaded = TfIdfModel::new(String::from(path.to_str().unwrap()), 5);
+        assert_eq!(loaded.get_stats().0, 0);
+        loaded.load_model().unwrap();
+        assert_eq!(loaded.get_vocab(), model.get_vocab());
+        assert_eq!(loaded.get_score("cat"), model.get_score("cat"));
+        assert_eq!(loaded.get_idf("cat"), model.get_idf("cat"));
+        assert_eq!(loaded.predict("any"), model.predict("any"));
+        fs::remove_file(path).unwrap();
+    }
+}
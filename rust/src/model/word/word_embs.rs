@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use super::super::{BaseModel, Mapping};
+use super::WordModel;
+
+/// Supported files with pretrained word vectors
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EmbeddingFormat {
+    /// word2vec binary format: `vocab_size dim` header, then `word<SPACE>` + dim little-endian f32
+    Word2VecBinary,
+    /// GloVe text format: `word v1 v2 ...` on each line, without header
+    GloVe,
+    /// fastText `.vec` text format: `vocab_size dim` header, then `word v1 v2 ...` on each line
+    FastText,
+}
+
+impl EmbeddingFormat {
+    /// Parse name of format ('word2vec', 'glove', 'fasttext')
+    pub fn from_name(name: &str) -> Option<Self> {
+        match &name.trim().to_lowercase()[..] {
+            "word2vec" => Some(EmbeddingFormat::Word2VecBinary),
+            "glove" => Some(EmbeddingFormat::GloVe),
+            "fasttext" => Some(EmbeddingFormat::FastText),
+            _ => None,
+        }
+    }
+
+    /// Detect format by file extension: '.bin' - word2vec, '.vec' - fastText, other - GloVe
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("bin") => EmbeddingFormat::Word2VecBinary,
+            Some("vec") => EmbeddingFormat::FastText,
+            _ => EmbeddingFormat::GloVe,
+        }
+    }
+}
+
+/// Word embeddings model - replace word with one of its nearest neighbours by cosine similarity
+pub struct WordEmbsModel {
+    /// Path on your system to load vectors
+    model_path: String,
+    /// Format of file with vectors
+    format: EmbeddingFormat,
+    /// If set, only first N words of file are loaded
+    max_num_vocab: Option<usize>,
+    /// Number of nearest neighbours, that can replace word
+    top_k: usize,
+    /// Word -> row index in vectors
+    vocab: HashMap<String, usize>,
+    /// Words in file order
+    words: Vec<String>,
+    /// Dimension of vectors
+    dim: usize,
+    /// Normalized vectors, stored row by row
+    vectors: Vec<f32>,
+    /// Lazy calculated nearest neighbours of every word
+    neighbours: Vec<OnceLock<Vec<String>>>,
+}
+
+impl WordEmbsModel {
+    pub fn new(
+        model_path: String,
+        format: Option<EmbeddingFormat>,
+        max_num_vocab: Option<usize>,
+        top_k: usize,
+    ) -> Self {
+        let format = format.unwrap_or_else(|| EmbeddingFormat::from_path(Path::new(&model_path)));
+        Self {
+            model_path,
+            format,
+            max_num_vocab,
+            top_k,
+            vocab: HashMap::new(),
+            words: Vec::new(),
+            dim: 0,
+            vectors: Vec::new(),
+            neighbours: Vec::new(),
+        }
+    }
+
+    /// Create model from pairs (word, vector), all vectors must have the same dimension
+    pub fn from_vectors(vectors: Vec<(String, Vec<f32>)>, top_k: usize) -> Self {
+        let mut model = Self::new(
+            String::from("internal vectors"),
+            Some(EmbeddingFormat::GloVe),
+            None,
+            top_k,
+        );
+        for (word, vector) in vectors {
+            model.add_vector(word, vector);
+        }
+        model.init_neighbours();
+        model
+    }
+
+    fn vocab_is_full(&self) -> bool {
+        match self.max_num_vocab {
+            Some(max_num_vocab) => self.words.len() >= max_num_vocab,
+            None => false,
+        }
+    }
+
+    /// Add normalized vector to model, duplicated words and vectors with wrong dimension are skipped
+    fn add_vector(&mut self, word: String, mut vector: Vec<f32>) {
+        if self.dim == 0 {
+            self.dim = vector.len();
+        }
+        if (vector.len() != self.dim) | self.vocab.contains_key(&word) | self.vocab_is_full() {
+            return;
+        }
+        let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        self.vocab.insert(word.clone(), self.words.len());
+        self.words.push(word);
+        self.vectors.extend(vector);
+    }
+
+    fn init_neighbours(&mut self) {
+        self.neighbours = (0..self.words.len()).map(|_| OnceLock::new()).collect();
+    }
+
+    /// Read text file, where each line is `word v1 v2 ...`
+    ///
+    /// Header `vocab_size dim` is skipped, if it exists
+    fn read_text_vectors(&mut self, path: &Path, with_header: bool) -> Result<(), Box<dyn Error>> {
+        let file_content = fs::read_to_string(path)?;
+        let mut lines = file_content.lines();
+        if with_header {
+            let header = lines.next().unwrap_or("");
+            self.dim = header.split_whitespace().nth(1).unwrap_or("0").parse()?;
+        }
+        for line in lines {
+            if self.vocab_is_full() {
+                break;
+            }
+            let mut parts = line.split_whitespace();
+            let word = match parts.next() {
+                Some(word) => word.to_string(),
+                None => continue,
+            };
+            let vector = parts
+                .map(|x| x.parse::<f32>())
+                .collect::<Result<Vec<f32>, _>>()?;
+            self.add_vector(word, vector);
+        }
+        Ok(())
+    }
+
+    /// Read word2vec binary file
+    fn read_binary_vectors(&mut self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let file_content = fs::read(path)?;
+        let header_end = file_content
+            .iter()
+            .position(|b| *b == b'\n')
+            .ok_or("word2vec header is not found")?;
+        let header = String::from_utf8_lossy(&file_content[..header_end]).to_string();
+        let mut header_parts = header.split_whitespace();
+        let vocab_size: usize = header_parts.next().unwrap_or("0").parse()?;
+        self.dim = header_parts.next().unwrap_or("0").parse()?;
+
+        let vector_bytes = self.dim * 4;
+        let mut pos = header_end + 1;
+        for _ in 0..vocab_size {
+            if self.vocab_is_full() {
+                break;
+            }
+            while pos < file_content.len() && file_content[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+            let word_len = file_content[pos..]
+                .iter()
+                .position(|b| *b == b' ')
+                .ok_or("word2vec file is truncated")?;
+            let word = String::from_utf8_lossy(&file_content[pos..pos + word_len]).to_string();
+            pos += word_len + 1;
+            if pos + vector_bytes > file_content.len() {
+                return Err("word2vec file is truncated".into());
+            }
+            let vector = file_content[pos..pos + vector_bytes]
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect();
+            pos += vector_bytes;
+            self.add_vector(word, vector);
+        }
+        Ok(())
+    }
+
+    /// Lazy-file read, before this method executed -> model is empty
+    pub fn load_model(&mut self) -> Result<(), Box<dyn Error>> {
+        if !self.words.is_empty() {
+            return Ok(());
+        }
+        let model_path = String::from(&self.model_path);
+        let model_path = Path::new(&model_path);
+        match self.format {
+            EmbeddingFormat::Word2VecBinary => self.read_binary_vectors(model_path),
+            EmbeddingFormat::GloVe => self.read_text_vectors(model_path, false),
+            EmbeddingFormat::FastText => self.read_text_vectors(model_path, true),
+        }
+        .map_err(|err| format!("{}: {}", model_path.display(), err))?;
+        self.init_neighbours();
+        Ok(())
+    }
+
+    fn get_vector(&self, idx: usize) -> &[f32] {
+        &self.vectors[idx * self.dim..(idx + 1) * self.dim]
+    }
+
+    /// Find top_k words with the highest cosine similarity,
+    /// the word itself and its case variants are skipped
+    fn calculate_neighbours(&self, idx: usize) -> Vec<String> {
+        let target = self.get_vector(idx);
+        let target_word = self.words[idx].to_lowercase();
+        let mut similarities: Vec<(usize, f32)> = (0..self.words.len())
+            .filter(|other| self.words[*other].to_lowercase() != target_word)
+            .map(|other| {
+                let vector = self.get_vector(other);
+                let cosine = target.iter().zip(vector).map(|(a, b)| a * b).sum();
+                (other, cosine)
+            })
+            .collect();
+        let by_similarity = |a: &(usize, f32), b: &(usize, f32)| -> Ordering {
+            b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0))
+        };
+        if similarities.len() > self.top_k && self.top_k > 0 {
+            similarities.select_nth_unstable_by(self.top_k - 1, by_similarity);
+        }
+        similarities.truncate(self.top_k);
+        similarities.sort_by(by_similarity);
+        similarities
+            .into_iter()
+            .map(|(other, _)| self.words[other].clone())
+            .collect()
+    }
+
+    /// Cosine similarity of two words, if both of them exist in model
+    pub fn similarity(&self, a: &str, b: &str) -> Option<f32> {
+        let a = self.get_vector(*self.vocab.get(a)?);
+        let b = self.get_vector(*self.vocab.get(b)?);
+        Some(a.iter().zip(b).map(|(x, y)| x * y).sum())
+    }
+
+    pub fn get_dim(&self) -> usize {
+        self.dim
+    }
+}
+
+impl BaseModel for WordEmbsModel {
+    fn get_mapping(&self) -> Option<&Mapping> {
+        None
+    }
+
+    fn get_stats(&self) -> (usize, usize, Vec<(usize, usize)>) {
+        (self.vocab.len(), self.vocab.capacity(), Vec::new())
+    }
+
+    fn key_exists(&self, data: &str) -> bool {
+        self.vocab.contains_key(data)
+    }
+
+    /// Nearest neighbours are calculated on the first call for each word and then cached
+    fn predict(&self, data: &str) -> Option<&Vec<String>> {
+        let idx = *self.vocab.get(data)?;
+        let neighbours = self.neighbours[idx].get_or_init(|| self.calculate_neighbours(idx));
+        match neighbours.len() {
+            0 => None,
+            _ => Some(neighbours),
+        }
+    }
+}
+
+impl WordModel for WordEmbsModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn str_vec(values: &[&str]) -> Vec<String> {
+        values.iter().map(|x| x.to_string()).collect()
+    }
+
+    #[test]
+    fn test_load_all_formats() {
+        let paths = [
+            "test_res/embs_glove.txt",
+            "test_res/embs_fasttext.vec",
+            "test_res/embs_word2vec.bin",
+        ];
+        for path in paths {
+            let mut model = WordEmbsModel::new(String::from(path), None, None, 2);
+            assert_eq!(model.get_stats().0, 0);
+            model.load_model().unwrap();
+            assert_eq!(model.get_stats().0, 7);
+            assert_eq!(model.get_dim(), 3);
+            assert_eq!(model.predict("king"), Some(&str_vec(&["queen", "woman"])));
+            assert_eq!(model.predict("apple"), Some(&str_vec(&["fruit", "banana"])));
+            assert_eq!(model.predict("car"), None);
+        }
+    }
+
+    #[test]
+    fn test_format_detection() {
+        let cases = [
+            ("vectors.bin", EmbeddingFormat::Word2VecBinary),
+            ("vectors.vec", EmbeddingFormat::FastText),
+            ("vectors.txt", EmbeddingFormat::GloVe),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(EmbeddingFormat::from_path(Path::new(path)), expected);
+        }
+        assert_eq!(
+            EmbeddingFormat::from_name("FastText"),
+            Some(EmbeddingFormat::FastText)
+        );
+        assert_eq!(EmbeddingFormat::from_name("bert"), None);
+    }
+
+    #[test]
+    fn test_max_num_vocab() {
+        let mut model = WordEmbsModel::new(
+            String::from("test_res/embs_word2vec.bin"),
+            None,
+            Some(4),
+            10,
+        );
+        model.load_model().unwrap();
+        assert_eq!(model.get_stats().0, 4);
+        assert!(!model.key_exists("apple"));
+        assert_eq!(
+            model.predict("man"),
+            Some(&str_vec(&["woman", "king", "queen"]))
+        );
+    }
+
+    #[test]
+    fn test_from_vectors() {
+        let model = WordEmbsModel::from_vectors(
+            vec![
+                (String::from("up"), vec![1.0, 0.0]),
+                (String::from("Up"), vec![1.0, 0.1]),
+                (String::from("right"), vec![0.0, 1.0]),
+                (String::from("upright"), vec![1.0, 1.0]),
+                (String::from("broken"), vec![1.0]),
+            ],
+            1,
+        );
+        assert_eq!(model.get_stats().0, 4);
+        assert_eq!(model.predict("up"), Some(&str_vec(&["upright"])));
+        assert!(model.similarity("up", "right").unwrap().abs() < 1e-6);
+        assert_eq!(model.similarity("up", "broken"), None);
+    }
+}
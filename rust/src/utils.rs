@@ -3,9 +3,12 @@ use std::error::Error;
 use std::fs;
 use std::path::Path;
 
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use serde_json::{Map, Value};
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::model::Mapping;
+use crate::model::{Mapping, WeightedMapping};
 
 /// Read json from path and put in HashMap
 ///
@@ -38,6 +41,81 @@ pub fn read_mapping(
     Ok(mapping)
 }
 
+/// Read json from path and put in HashMap with weights of values
+///
+/// Expected json format String -> {String: f32}, e.g. `{"l": {"1": 0.7, "I": 0.3}}`.
+/// List format String -> Vec<String> is supported too, each value gets weight 1.0.
+/// Values with non-positive or non-numeric weight are skipped
+pub fn read_weighted_mapping(
+    path: &Path,
+    hashmap_init_capacity: Option<usize>,
+    vec_value_init_capacity: Option<usize>,
+) -> Result<WeightedMapping, Box<dyn Error>> {
+    let hashmap_init_capacity: usize = hashmap_init_capacity.unwrap_or(100);
+    let vec_value_init_capacity: usize = vec_value_init_capacity.unwrap_or(10);
+
+    let mut mapping: WeightedMapping = HashMap::with_capacity(hashmap_init_capacity);
+    let file_content = fs::read_to_string(path)?;
+    let json_map: Map<String, Value> = serde_json::from_str(&file_content)?;
+
+    for (key, raw_value) in &json_map {
+        let mut values: HashMap<String, f32> = HashMap::with_capacity(vec_value_init_capacity);
+        match raw_value {
+            Value::Array(json_vec) => {
+                for json_value in json_vec {
+                    if let Value::String(s) = json_value {
+                        values.insert(s.to_string(), 1.0);
+                    }
+                }
+            }
+            Value::Object(json_weights) => {
+                for (value, raw_weight) in json_weights {
+                    if let Some(weight) = raw_weight.as_f64() {
+                        if weight > 0.0 {
+                            values.insert(value.to_string(), weight as f32);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+        if !values.is_empty() {
+            mapping.insert(key.to_string(), values);
+        }
+    }
+    Ok(mapping)
+}
+
+/// Read plain text file from path and put in HashMap
+///
+/// Expected format: one record per line, `key value_1 value_2 ...` separated by whitespace.
+/// If key is met more than once, values are merged
+pub fn read_text_mapping(
+    path: &Path,
+    hashmap_init_capacity: Option<usize>,
+    vec_value_init_capacity: Option<usize>,
+) -> Result<Mapping, Box<dyn Error>> {
+    let hashmap_init_capacity: usize = hashmap_init_capacity.unwrap_or(100);
+    let vec_value_init_capacity: usize = vec_value_init_capacity.unwrap_or(10);
+
+    let mut mapping: Mapping = HashMap::with_capacity(hashmap_init_capacity);
+    let file_content = fs::read_to_string(path)?;
+
+    for line in file_content.lines() {
+        let mut parts = line.split_whitespace();
+        if let Some(key) = parts.next() {
+            let values: Vec<String> = parts.map(String::from).collect();
+            if !values.is_empty() {
+                mapping
+                    .entry(key.to_string())
+                    .or_insert_with(|| Vec::with_capacity(vec_value_init_capacity))
+                    .extend(values);
+            }
+        }
+    }
+    Ok(mapping)
+}
+
 pub type MappingSet = HashMap<String, HashSet<String>>;
 
 /// Useful function in unit-tests, not necessary in main lib module
@@ -58,6 +136,24 @@ pub fn get_chars_len(input: &str) -> usize {
     counter
 }
 
+/// Get number of extended grapheme clusters in string
+///
+/// 'й' written as 'и' + combining breve is two chars, but one grapheme
+pub fn get_graphemes_len(input: &str) -> usize {
+    input.graphemes(true).count()
+}
+
+/// Split string to chars or to extended grapheme clusters
+pub fn split_chars(input: &str, use_graphemes: bool) -> Vec<&str> {
+    match use_graphemes {
+        true => input.graphemes(true).collect(),
+        false => input
+            .char_indices()
+            .map(|(idx, ch)| &input[idx..idx + ch.len_utf8()])
+            .collect(),
+    }
+}
+
 /// Split n to chunks
 ///
 /// Used in calculations: how many tasks go into a particular thread
@@ -91,6 +187,33 @@ pub fn split_to_chunks_indexes(vec_size: usize, num_chunks: usize) -> Vec<(usize
     result
 }
 
+/// Step of SplitMix64 between seeds of neighbouring items
+const SEED_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Derive seed of `idx`-th item from base seed
+///
+/// Uses SplitMix64 finalizer, so seeds of neighbouring items are not correlated
+pub fn derive_seed(seed: u64, idx: usize) -> u64 {
+    let mut z = seed.wrapping_add((idx as u64).wrapping_add(1).wrapping_mul(SEED_GAMMA));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Base seed, whose `idx`-th item gets the same seed, as `first_idx + idx`-th item of `seed`
+///
+/// So list, augmented in parts, gets the same result, as if it was augmented at once
+pub fn offset_seed(seed: u64, first_idx: usize) -> u64 {
+    seed.wrapping_add((first_idx as u64).wrapping_mul(SEED_GAMMA))
+}
+
+/// Create RNG for `idx`-th item
+///
+/// Item gets the same RNG, no matter how items are split between threads
+pub fn item_rng(seed: u64, idx: usize) -> StdRng {
+    StdRng::seed_from_u64(derive_seed(seed, idx))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -146,6 +269,64 @@ mod tests {
         assert_eq!(err.kind(), io::ErrorKind::NotFound);
     }
 
+    #[test]
+    fn test_read_weighted_mapping() {
+        let weighted_mapping_path = Path::new("test_res/weighted_mapping.json");
+        let readed_mapping = read_weighted_mapping(weighted_mapping_path, None, None).unwrap();
+        let expected_mapping = HashMap::from([
+            (
+                String::from("l"),
+                HashMap::from([(String::from("1"), 0.7), (String::from("I"), 0.3)]),
+            ),
+            (
+                String::from("0"),
+                HashMap::from([(String::from("O"), 1.0), (String::from("o"), 1.0)]),
+            ),
+            (String::from("B"), HashMap::from([(String::from("8"), 1.0)])),
+        ]);
+        assert_eq!(readed_mapping, expected_mapping);
+    }
+
+    #[test]
+    fn test_read_weighted_mapping_list_format() {
+        let good_mapping_path = Path::new("test_res/good_mapping.json");
+        let weighted = read_weighted_mapping(good_mapping_path, None, None).unwrap();
+        let mapping = read_mapping(good_mapping_path, None, None).unwrap();
+        assert_eq!(weighted.len(), mapping.len());
+        for (key, values) in mapping {
+            for value in values {
+                assert_eq!(weighted[&key][&value], 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_text_mapping() {
+        let text_mapping_path = Path::new("test_res/spelling_en.txt");
+        let readed_mapping: Mapping = read_text_mapping(text_mapping_path, None, None).unwrap();
+        let expected_mapping = HashMap::from([
+            (
+                String::from("because"),
+                vec![String::from("becuase"), String::from("becasue")],
+            ),
+            (
+                String::from("receive"),
+                vec![String::from("recieve"), String::from("receve")],
+            ),
+            (String::from("the"), vec![String::from("teh")]),
+        ]);
+        assert_eq!(readed_mapping, expected_mapping);
+    }
+
+    #[test]
+    fn test_read_not_exist_text_mapping() {
+        let not_exist_path = Path::new("test_res/not_exist.txt");
+        let readed_result: Result<Mapping, Box<dyn Error>> =
+            read_text_mapping(not_exist_path, None, None);
+        let err = readed_result.unwrap_err().downcast::<io::Error>().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
     #[test]
     fn test_transform_to_set() {
         let input_mapping = HashMap::from([
@@ -190,6 +371,16 @@ mod tests {
         assert_eq!(chars_count, 7)
     }
 
+    #[test]
+    fn test_split_chars_graphemes() {
+        // Flag, emoji ZWJ sequence and Hindi cluster are single graphemes
+        let input = "🇷🇺👨‍👩‍👧नि";
+        assert_eq!(get_graphemes_len(input), 3);
+        assert_eq!(split_chars(input, true), vec!["🇷🇺", "👨‍👩‍👧", "नि"]);
+        assert_eq!(split_chars(input, false).len(), get_chars_len(input));
+        assert_eq!(split_chars(input, false).concat(), input);
+    }
+
     #[test]
     fn test_cyrillic_char_counter() {
         let input: &str = "Пример";
@@ -263,4 +454,26 @@ mod tests {
         assert_eq!(&arr[chunk_idxs[1].0..chunk_idxs[1].1], vec![2]);
         assert_eq!(&arr[chunk_idxs[2].0..chunk_idxs[2].1], Vec::<i32>::new());
     }
+
+    #[test]
+    fn test_derive_seed() {
+        assert_eq!(derive_seed(42, 0), derive_seed(42, 0));
+        assert_ne!(derive_seed(42, 0), derive_seed(42, 1));
+        assert_ne!(derive_seed(42, 0), derive_seed(43, 0));
+    }
+
+    #[test]
+    fn test_offset_seed() {
+        assert_eq!(offset_seed(42, 0), 42);
+        for idx in 0..3 {
+            assert_eq!(
+                derive_seed(offset_seed(42, 5), idx),
+                derive_seed(42, 5 + idx)
+            );
+            assert_eq!(
+                derive_seed(offset_seed(u64::MAX, 7), idx),
+                derive_seed(u64::MAX, 7 + idx)
+            );
+        }
+    }
 }
@@ -0,0 +1,17 @@
+s(102958343,1,'car',n,1,71).
+s(102958343,2,'auto',n,1,0).
+s(102958343,3,'automobile',n,1,2).
+s(102958343,4,'motorcar',n,1,0).
+s(107697537,1,'hotdog',n,1,0).
+s(107697537,2,'hot dog',n,1,0).
+s(107697537,3,'red_hot',n,1,0).
+s(201835496,1,'travel',v,1,57).
+s(201835496,2,'go',v,1,56).
+s(201835496,3,'move',v,1,9).
+s(201835496,4,'locomote',v,1,0).
+s(302036578,1,'fast',a,1,55).
+s(302036578,2,'quick',s,1,10).
+s(400086210,1,'fast',r,1,8).
+s(400086210,2,'quickly',r,1,0).
+s(104243251,1,'o''clock',n,1,0).
+s(104243251,2,'hour',n,1,0).